@@ -1,21 +1,41 @@
 use crate::{
     credential::Credential,
     domain::{
-        AccountBalance, CurrencyPair, ListBalancesResponse, ListOrdersResponse,
-        ListTickersResponse, ListTradesResponse, Order, OrderBook, Ticker, Trade,
+        Account, AccountBalance, Asset, Beneficiary, Candle, CandleDuration, CreateAccountRequest,
+        CreateFundingAddressRequest, CurrencyPair, FeeInfo, FundingAddress, FundsMove,
+        GetCandlesQuery, GetCandlesResponse, GetFundingAddressQuery, GetMarketsInfoResponse,
+        GetMoveQuery, GetOrderQuery, ListBalancesResponse, ListBeneficiariesResponse,
+        ListFundingAddressesResponse, ListMovesQuery, ListMovesResponse, ListOrdersQuery,
+        ListOrdersResponse, ListPendingTransactionsResponse, ListTickersResponse,
+        ListTradesResponse, ListTransactionsQuery, ListTransactionsResponse, ListTransfersQuery,
+        ListTransfersResponse, ListUserTradesQuery, ListUserTradesResponse, MarketsInfo,
+        MoveFundsRequest, MoveFundsResponse, MoveReference, Order, OrderBook, OrderDetails,
+        OrderReference, OrderState, PostMarketOrderRequest, PostOrderRequest, PostOrderResponse,
+        SendFee, SendFeeQuery, SendRequest, SendResponse, StopOrderRequest, SuccessResponse,
+        Ticker, Trade, Transaction, Transfer, UpdateAccountNameRequest, UserTrade,
     },
     error::Error,
-    http::Http,
+    http::{with_repeated_query, Http},
+    paginate,
+    streaming::{MarketStream, UserStream},
 };
 use async_std::task;
 use chrono::{DateTime, Utc};
-use futures_util::{stream, StreamExt};
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
+use surf::http::Method;
+
+const RESOLVE_ORDER_ATTEMPTS: usize = 10;
+const RESOLVE_ORDER_INTERVAL: Duration = Duration::from_millis(500);
+const CANCEL_ALL_CONCURRENCY: usize = 5;
+
 pub struct LunoClientBuilder {
     credential: Credential,
     timeout: Duration,
     enable_logger_middleware: bool,
+    stream_url: String,
 }
 
 impl LunoClientBuilder {
@@ -26,6 +46,7 @@ impl LunoClientBuilder {
             credential,
             timeout: Duration::from_millis(60000),
             enable_logger_middleware: false,
+            stream_url: "wss://ws.luno.com".to_string(),
         }
     }
 
@@ -41,33 +62,47 @@ impl LunoClientBuilder {
         self
     }
 
+    /// Change the base URL of the streaming API, for example to a local stand-in for tests
+    pub fn with_stream_url<T: AsRef<str>>(mut self, stream_url: T) -> Self {
+        self.stream_url = stream_url.as_ref().trim_end_matches('/').into();
+        self
+    }
+
     /// Build LunoClientBuilder into a LunoClient
     pub fn build(self) -> LunoClient {
-        LunoClient::new_with_features(self.credential, self.timeout, self.enable_logger_middleware)
+        LunoClient::new_with_features(
+            self.credential,
+            self.timeout,
+            self.enable_logger_middleware,
+            self.stream_url,
+        )
     }
 }
 
+#[derive(Clone)]
 pub struct LunoClient {
-    http: Arc<Http>,
+    pub(crate) http: Arc<Http>,
+    pub(crate) credential: Credential,
+    pub(crate) stream_url: String,
 }
 
 impl LunoClient {
     /// Create a new LunoClient
     pub fn new<T: AsRef<str>>(key_id: T, key_secret: T) -> Self {
-        let http = Http::new(key_id.as_ref().into(), key_secret.as_ref().into());
-        LunoClient {
-            http: Arc::new(http),
-        }
+        LunoClientBuilder::new(key_id.as_ref().into(), key_secret.as_ref().into()).build()
     }
 
     fn new_with_features(
         credential: Credential,
         timeout: Duration,
         enable_logger_middleware: bool,
+        stream_url: String,
     ) -> Self {
-        let http = Http::new_with_features(credential, timeout, enable_logger_middleware);
+        let http = Http::new_with_features(credential.clone(), timeout, enable_logger_middleware);
         LunoClient {
             http: Arc::new(http),
+            credential,
+            stream_url,
         }
     }
 
@@ -77,13 +112,601 @@ impl LunoClient {
         Ok(response.balances)
     }
 
-    /// List all orders on Luno profile
+    /// List the balances of the accounts holding the given assets
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{Asset, BalanceSet, LunoClient};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let balances = client
+    ///     .list_balances_for(&[Asset::XBT, Asset::NGN])
+    ///     .await
+    ///     .unwrap();
+    /// let balance_set = BalanceSet::new(balances);
+    /// for asset in &[Asset::XBT, Asset::NGN] {
+    ///     println!("{} -> {:?}", asset, balance_set.total(*asset).unwrap());
+    /// }
+    /// # }
+    /// ```
+    pub async fn list_balances_for(&self, assets: &[Asset]) -> Result<Vec<AccountBalance>, Error> {
+        let path = with_repeated_query("/api/1/balance", "assets", assets);
+        let response: ListBalancesResponse = self.http.process_request(path).await?;
+        Ok(response.balances)
+    }
+
+    /// Create an additional account for a currency on Luno profile
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::LunoClient;
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let account = client.create_account("XBT", "Market making").await.unwrap();
+    /// println!("Created account {} ({})", account.id, account.name);
+    /// # }
+    /// ```
+    pub async fn create_account<T: AsRef<str>>(
+        &self,
+        currency: T,
+        name: T,
+    ) -> Result<Account, Error> {
+        let request = CreateAccountRequest::new(currency.as_ref(), name.as_ref());
+        let response: Account = self
+            .http
+            .process_form_request(Method::Post, "/api/1/accounts", &request)
+            .await?;
+        Ok(response)
+    }
+
+    /// Change the name of an account. Returns whether Luno accepted the new name
+    pub async fn update_account_name<T: AsRef<str>>(
+        &self,
+        account_id: T,
+        name: T,
+    ) -> Result<bool, Error> {
+        let path = format!("/api/1/accounts/{}/name", account_id.as_ref());
+        let request = UpdateAccountNameRequest::new(name.as_ref());
+        let response: SuccessResponse = self
+            .http
+            .process_form_request(Method::Put, path, &request)
+            .await?;
+        Ok(response.success)
+    }
+
+    /// List the transactions of an account between `min_row` (inclusive) and `max_row`
+    /// (exclusive). Rows are numbered from 1 and at most 1000 rows can be listed per call.
+    pub async fn list_transactions<T: AsRef<str>>(
+        &self,
+        account_id: T,
+        min_row: i64,
+        max_row: i64,
+    ) -> Result<Vec<Transaction>, Error> {
+        let path = format!("/api/1/accounts/{}/transactions", account_id.as_ref());
+        let query = ListTransactionsQuery::new(min_row, max_row);
+        let response: ListTransactionsResponse =
+            self.http.process_query_request(path, &query).await?;
+        Ok(response.transactions.unwrap_or_default())
+    }
+
+    /// Stream the full transaction ledger of an account, oldest first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use luno_rs::LunoClient;
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let mut transactions = Box::pin(client.list_transactions_stream("319232323"));
+    /// while let Some(transaction) = transactions.next().await {
+    ///     let transaction = transaction.unwrap();
+    ///     println!("{} -> {} ({})", transaction.row_index, transaction.balance_delta, transaction.description);
+    /// }
+    /// # }
+    /// ```
+    pub fn list_transactions_stream<T: AsRef<str>>(
+        &self,
+        account_id: T,
+    ) -> impl Stream<Item = Result<Transaction, Error>> {
+        paginate::transactions(self.http.clone(), account_id.as_ref().into())
+    }
+
+    /// List the unconfirmed transactions of an account. These are counted in
+    /// `AccountBalance.unconfirmed` rather than in `AccountBalance.balance`
+    pub async fn list_pending_transactions<T: AsRef<str>>(
+        &self,
+        account_id: T,
+    ) -> Result<Vec<Transaction>, Error> {
+        let path = format!("/api/1/accounts/{}/pending", account_id.as_ref());
+        let response: ListPendingTransactionsResponse = self.http.process_request(path).await?;
+        Ok(response.pending.unwrap_or_default())
+    }
+
+    /// List the bank accounts that withdrawals can be paid out to
+    pub async fn list_beneficiaries(&self) -> Result<Vec<Beneficiary>, Error> {
+        let response: ListBeneficiariesResponse =
+            self.http.process_request("/api/1/beneficiaries").await?;
+        Ok(response.beneficiaries.unwrap_or_default())
+    }
+
+    /// List the on-chain and bank transfers of an account, newest first, optionally only
+    /// those created before a point in time
+    pub async fn list_transfers(
+        &self,
+        account_id: &str,
+        before: Option<DateTime<Utc>>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Transfer>, Error> {
+        let query = ListTransfersQuery::new(account_id, before, limit);
+        let response: ListTransfersResponse = self
+            .http
+            .process_query_request("/api/exchange/1/transfers", &query)
+            .await?;
+        Ok(response.transfers.unwrap_or_default())
+    }
+
+    /// Send cryptocurrency to an address or to the email address of a Luno user and return
+    /// the id of the withdrawal that pays it out
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{Asset, LunoClient, SendRequestBuilder};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let address = "rLuno1ExampLeXRPaDdRe55";
+    /// let fee = client.send_fee("25", Asset::XRP, address).await.unwrap();
+    /// println!("Sending will cost {} {}", fee.fee, fee.currency);
+    /// let request = SendRequestBuilder::new("25", Asset::XRP, address)
+    ///     .with_destination_tag(1234567)
+    ///     .with_external_id("payout-42")
+    ///     .build();
+    /// let withdrawal_id = client.send(request).await.unwrap();
+    /// println!("Sent with withdrawal {}", withdrawal_id);
+    /// # }
+    /// ```
+    pub async fn send(&self, request: SendRequest) -> Result<String, Error> {
+        let response: SendResponse = self
+            .http
+            .process_form_request(Method::Post, "/api/1/send", &request)
+            .await?;
+        Ok(response.withdrawal_id)
+    }
+
+    /// Estimate the fee to send `amount` of `currency` to an address
+    pub async fn send_fee<A: fmt::Display>(
+        &self,
+        amount: A,
+        currency: Asset,
+        address: &str,
+    ) -> Result<SendFee, Error> {
+        let query = SendFeeQuery::new(amount, currency, address);
+        let response: SendFee = self
+            .http
+            .process_query_request("/api/1/send_fee", &query)
+            .await?;
+        Ok(response)
+    }
+
+    /// Move funds between two accounts of the same currency and return the id of the move.
+    ///
+    /// When the request has a client move id that was already used, the id of the existing
+    /// move is returned instead, so a retried request never moves the funds twice.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{LunoClient, MoveFundsRequestBuilder, MoveReference};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let request = MoveFundsRequestBuilder::new("10000.00", "12345678910", "12345678911")
+    ///     .with_client_move_id("rebalance-2021-01-04")
+    ///     .build();
+    /// let move_id = client.move_funds(request).await.unwrap();
+    /// let funds_move = client.get_move(MoveReference::MoveId(move_id)).await.unwrap();
+    /// println!("{:?}", funds_move.status);
+    /// # }
+    /// ```
+    pub async fn move_funds(&self, request: MoveFundsRequest) -> Result<String, Error> {
+        let response: Result<MoveFundsResponse, Error> = self
+            .http
+            .process_form_request(Method::Post, "/api/exchange/1/move", &request)
+            .await;
+        match (response, request.client_move_id()) {
+            (Err(Error::ApiError(e)), Some(client_move_id))
+                if e.error_code == "ErrDuplicateClientMoveID" =>
+            {
+                let reference = MoveReference::ClientMoveId(client_move_id.into());
+                Ok(self.get_move(reference).await?.id)
+            }
+            (response, _) => Ok(response?.id),
+        }
+    }
+
+    /// Get a move by its Luno id or by the client move id it was requested with
+    pub async fn get_move(&self, reference: MoveReference) -> Result<FundsMove, Error> {
+        let query = GetMoveQuery::from(reference);
+        let response: FundsMove = self
+            .http
+            .process_query_request("/api/exchange/1/move", &query)
+            .await?;
+        Ok(response)
+    }
+
+    /// List the moves on Luno profile, newest first, optionally only those created before
+    /// a point in time
+    pub async fn list_moves(
+        &self,
+        before: Option<DateTime<Utc>>,
+        limit: Option<u32>,
+    ) -> Result<Vec<FundsMove>, Error> {
+        let query = ListMovesQuery::new(before, limit);
+        let response: ListMovesResponse = self
+            .http
+            .process_query_request("/api/exchange/1/move/list_moves", &query)
+            .await?;
+        Ok(response.moves.unwrap_or_default())
+    }
+
+    /// Get the default receive address for an asset, or a specific one of its receive addresses
+    pub async fn get_funding_address(
+        &self,
+        asset: Asset,
+        address: Option<&str>,
+    ) -> Result<FundingAddress, Error> {
+        let query = GetFundingAddressQuery::new(asset, address);
+        let response: FundingAddress = self
+            .http
+            .process_query_request("/api/1/funding_address", &query)
+            .await?;
+        Ok(response)
+    }
+
+    /// Create a new receive address for an asset, optionally named and assigned to a
+    /// specific account
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{Asset, LunoClient};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let address = client
+    ///     .create_funding_address(Asset::XBT, Some("customer-42"), None)
+    ///     .await
+    ///     .unwrap();
+    /// println!("Deposit to {} ({})", address.address, address.qr_code_uri);
+    /// # }
+    /// ```
+    pub async fn create_funding_address(
+        &self,
+        asset: Asset,
+        name: Option<&str>,
+        account_id: Option<&str>,
+    ) -> Result<FundingAddress, Error> {
+        let request = CreateFundingAddressRequest::new(asset, name, account_id);
+        let response: FundingAddress = self
+            .http
+            .process_form_request(Method::Post, "/api/1/funding_address", &request)
+            .await?;
+        Ok(response)
+    }
+
+    /// List all receive addresses for an asset
+    pub async fn list_funding_addresses(&self, asset: Asset) -> Result<Vec<FundingAddress>, Error> {
+        let path = format!("/api/1/funding_address/list?asset={}", asset);
+        let response: ListFundingAddressesResponse = self.http.process_request(path).await?;
+        Ok(response.addresses.unwrap_or_default())
+    }
+
+    /// List all pending orders on Luno profile
     pub async fn list_orders(&self) -> Result<Vec<Order>, Error> {
+        self.list_orders_with_query(ListOrdersQuery::new().with_state(OrderState::Pending))
+            .await
+    }
+
+    /// List orders on Luno profile matching a query
+    pub async fn list_orders_with_query(
+        &self,
+        query: ListOrdersQuery,
+    ) -> Result<Vec<Order>, Error> {
         let response: ListOrdersResponse = self
             .http
-            .process_request("/api/1/listorders?state=PENDING")
+            .process_query_request("/api/1/listorders", &query)
             .await?;
-        Ok(response.orders)
+        Ok(response.orders.unwrap_or_default())
+    }
+
+    /// Stream all orders on Luno profile matching a query, newest first.
+    ///
+    /// Pages are fetched as the stream is consumed by moving `created_before` further back
+    /// in time. The limit of the query, if any, is used as the page size.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use luno_rs::{ListOrdersQuery, LunoClient, OrderState};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let query = ListOrdersQuery::new()
+    ///     .with_state(OrderState::Complete)
+    ///     .with_limit(100);
+    /// let mut orders = Box::pin(client.list_orders_stream(query));
+    /// while let Some(order) = orders.next().await {
+    ///     let order = order.unwrap();
+    ///     println!("{} -> {} {}", order.order_id, order.order_type, order.base);
+    /// }
+    /// # }
+    /// ```
+    pub fn list_orders_stream(
+        &self,
+        query: ListOrdersQuery,
+    ) -> impl Stream<Item = Result<Order, Error>> {
+        paginate::orders(self.http.clone(), query)
+    }
+
+    /// Place a limit order and return the id of the newly created order
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{CurrencyPair, LunoClient, OrderType, PostOrderRequestBuilder, TimeInForce};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let request =
+    ///     PostOrderRequestBuilder::new(CurrencyPair::XBTNGN, OrderType::BID, "12000000", "0.001")
+    ///         .with_post_only()
+    ///         .with_time_in_force(TimeInForce::GTC)
+    ///         .build();
+    /// let order_id = client.post_limit_order(request).await.unwrap();
+    /// println!("Placed order {}", order_id);
+    /// # }
+    /// ```
+    pub async fn post_limit_order(&self, request: PostOrderRequest) -> Result<String, Error> {
+        let response: PostOrderResponse = self
+            .http
+            .process_form_request(Method::Post, "/api/1/postorder", &request)
+            .await?;
+        Ok(response.order_id)
+    }
+
+    /// Place a market order and return the id of the newly created order.
+    ///
+    /// Use `resolve_order` with the returned id to find out how the order was filled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{CurrencyPair, LunoClient, PostMarketOrderRequestBuilder};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let request = PostMarketOrderRequestBuilder::buy(CurrencyPair::XBTNGN, "50000").build();
+    /// let order_id = client.post_market_order(request).await.unwrap();
+    /// let order = client.resolve_order(&order_id).await.unwrap();
+    /// println!("Bought {} XBT for {} NGN", order.base, order.counter);
+    /// # }
+    /// ```
+    pub async fn post_market_order(
+        &self,
+        request: PostMarketOrderRequest,
+    ) -> Result<String, Error> {
+        let response: PostOrderResponse = self
+            .http
+            .process_form_request(Method::Post, "/api/1/marketorder", &request)
+            .await?;
+        Ok(response.order_id)
+    }
+
+    /// Poll an order until it is no longer pending and return it.
+    ///
+    /// The order is returned as last seen if it is still pending after a few attempts.
+    pub async fn resolve_order(&self, order_id: &str) -> Result<Order, Error> {
+        let path = format!("/api/1/orders/{}", order_id);
+        let mut order: Order = self.http.process_request(&path).await?;
+        for _ in 0..RESOLVE_ORDER_ATTEMPTS {
+            if order.state != "PENDING" {
+                break;
+            }
+            task::sleep(RESOLVE_ORDER_INTERVAL).await;
+            order = self.http.process_request(&path).await?;
+        }
+        Ok(order)
+    }
+
+    /// Get an order by its Luno order id or by the client order id it was placed with
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{LunoClient, OrderReference};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let order = client
+    ///     .get_order(OrderReference::ClientOrderId("dca-2021-01-04".into()))
+    ///     .await
+    ///     .unwrap();
+    /// println!("{} -> {:?}, filled {}", order.order_id, order.status, order.base);
+    /// # }
+    /// ```
+    pub async fn get_order(&self, reference: OrderReference) -> Result<OrderDetails, Error> {
+        let query = GetOrderQuery::from(reference);
+        let response: OrderDetails = self
+            .http
+            .process_query_request("/api/exchange/3/order", &query)
+            .await?;
+        Ok(response)
+    }
+
+    /// Cancel an order. Returns whether Luno accepted the cancellation
+    pub async fn cancel_order(&self, order_id: &str) -> Result<bool, Error> {
+        let request = StopOrderRequest::new(order_id);
+        let response: SuccessResponse = self
+            .http
+            .process_form_request(Method::Post, "/api/1/stoporder", &request)
+            .await?;
+        Ok(response.success)
+    }
+
+    /// Cancel all pending orders, optionally only those for a currency pair. Every page of
+    /// pending orders is listed before any of them are cancelled.
+    ///
+    /// Orders are cancelled concurrently and the outcome of each cancellation is returned
+    /// alongside its order id, so one failed cancellation does not hide the others.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{CurrencyPair, LunoClient};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let outcomes = client.cancel_all(Some(CurrencyPair::XBTNGN)).await.unwrap();
+    /// for (order_id, outcome) in outcomes {
+    ///     match outcome {
+    ///         Ok(_) => println!("{} cancelled", order_id),
+    ///         Err(e) => println!("{} not cancelled: {}", order_id, e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn cancel_all(
+        &self,
+        currency_pair: Option<CurrencyPair>,
+    ) -> Result<Vec<(String, Result<bool, Error>)>, Error> {
+        let mut query = ListOrdersQuery::new().with_state(OrderState::Pending);
+        if let Some(cp) = currency_pair {
+            query = query.with_currency_pair(cp);
+        }
+        // Every page is read before cancelling so that cancellations do not shift the pages
+        let orders: Vec<Order> = self.list_orders_stream(query).try_collect().await?;
+        let outcomes = stream::iter(orders)
+            .map(|order| async move {
+                let outcome = self.cancel_order(&order.order_id).await;
+                (order.order_id, outcome)
+            })
+            .buffer_unordered(CANCEL_ALL_CONCURRENCY);
+        Ok(outcomes.collect().await)
+    }
+
+    /// Get the maker and taker fees charged on Luno profile for a currency pair
+    pub async fn get_fee_info(&self, currency_pair: CurrencyPair) -> Result<FeeInfo, Error> {
+        let path = format!("/api/1/fee_info?pair={}", currency_pair);
+        let response: FeeInfo = self.http.process_request(path).await?;
+        Ok(response)
+    }
+
+    /// Get trading limits and precision for the given currency pairs, or for all markets
+    /// when no currency pair is given
+    pub async fn get_markets(
+        &self,
+        currency_pairs: Vec<CurrencyPair>,
+    ) -> Result<Vec<MarketsInfo>, Error> {
+        let path = with_repeated_query("/api/exchange/1/markets", "pair", &currency_pairs);
+        let response: GetMarketsInfoResponse = self.http.process_request(path).await?;
+        Ok(response.markets)
+    }
+
+    /// Get candlesticks for a currency pair starting at `since`. At most 1000 candles are
+    /// returned per call.
+    pub async fn get_candles(
+        &self,
+        currency_pair: CurrencyPair,
+        since: DateTime<Utc>,
+        duration: CandleDuration,
+    ) -> Result<Vec<Candle>, Error> {
+        let query = GetCandlesQuery::new(currency_pair, since, duration);
+        let response: GetCandlesResponse = self
+            .http
+            .process_query_request("/api/exchange/1/candles", &query)
+            .await?;
+        Ok(response.candles)
+    }
+
+    /// Stream candlesticks for a currency pair from `since` up to `until`, fetching as many
+    /// pages as needed to cover the time range.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    /// use futures_util::StreamExt;
+    /// use luno_rs::{CandleDuration, CurrencyPair, LunoClient};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let until = Utc::now();
+    /// let since = until - Duration::days(30);
+    /// let mut candles = Box::pin(client.get_candles_between(
+    ///     CurrencyPair::XBTNGN,
+    ///     since,
+    ///     until,
+    ///     CandleDuration::OneHour,
+    /// ));
+    /// while let Some(candle) = candles.next().await {
+    ///     let candle = candle.unwrap();
+    ///     println!("{} -> O: {} H: {} L: {} C: {}", candle.timestamp, candle.open, candle.high, candle.low, candle.close);
+    /// }
+    /// # }
+    /// ```
+    pub fn get_candles_between(
+        &self,
+        currency_pair: CurrencyPair,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+        duration: CandleDuration,
+    ) -> impl Stream<Item = Result<Candle, Error>> {
+        let query = GetCandlesQuery::new(currency_pair, since, duration);
+        paginate::candles(self.http.clone(), query, until)
     }
 
     /// Get ticker for currency pair
@@ -131,6 +754,63 @@ impl LunoClient {
         Ok(response)
     }
 
+    /// Stream the order book and trades of a market over a WebSocket.
+    ///
+    /// Returns once the initial snapshot has been received, so `order_book()` is usable right away.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use luno_rs::{CurrencyPair, LunoClient};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let mut stream = client.market_stream(CurrencyPair::XBTZAR).await.unwrap();
+    /// while let Some(event) = stream.next().await {
+    ///     match event {
+    ///         Ok(event) => println!("{:?} -> best ask: {:?}", event, stream.order_book().asks.first()),
+    ///         Err(e) => println!("stream interrupted, reconnecting: {}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn market_stream(&self, currency_pair: CurrencyPair) -> Result<MarketStream, Error> {
+        let url = format!("{}/api/1/stream/{}", self.stream_url, currency_pair);
+        MarketStream::connect(url, self.credential.clone()).await
+    }
+
+    /// Stream order status changes, order fills and balance updates of the user over a WebSocket.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use luno_rs::{LunoClient, UserEvent};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let mut stream = client.user_stream().await.unwrap();
+    /// while let Some(event) = stream.next().await {
+    ///     match event {
+    ///         Ok(UserEvent::OrderFill(fill)) => println!("{} filled {}", fill.order_id, fill.base_delta),
+    ///         Ok(_) => {}
+    ///         Err(e) => println!("stream interrupted, reconnecting: {}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn user_stream(&self) -> Result<UserStream, Error> {
+        let url = format!("{}/api/1/userstream", self.stream_url);
+        UserStream::connect(url, self.credential.clone()).await
+    }
+
     /// List the most recent Trades for the specified currency pair in the last 24 hours. At most 100 results are returned per call.
     ///
     /// # Example
@@ -196,4 +876,89 @@ impl LunoClient {
         let response: ListTradesResponse = self.http.process_request(path).await?;
         Ok(response.trades)
     }
+
+    /// List trades executed by orders on Luno profile matching a query
+    pub async fn list_user_trades(
+        &self,
+        query: ListUserTradesQuery,
+    ) -> Result<Vec<UserTrade>, Error> {
+        let response: ListUserTradesResponse = self
+            .http
+            .process_query_request("/api/1/listtrades", &query)
+            .await?;
+        Ok(response.trades.unwrap_or_default())
+    }
+
+    /// Stream all trades executed by orders on Luno profile matching a query.
+    ///
+    /// Pages are fetched as the stream is consumed by moving the sequence cursor past the
+    /// last trade received. The limit of the query, if any, is used as the page size.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use luno_rs::{CurrencyPair, ListUserTradesQuery, LunoClient};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let query = ListUserTradesQuery::new(CurrencyPair::XBTNGN).with_limit(100);
+    /// let mut trades = Box::pin(client.list_user_trades_stream(query));
+    /// while let Some(trade) = trades.next().await {
+    ///     let trade = trade.unwrap();
+    ///     println!(
+    ///         "{} -> {} {} @ {}, fee {} / {}",
+    ///         trade.sequence, trade.order_type, trade.volume, trade.price, trade.fee_base, trade.fee_counter
+    ///     );
+    /// }
+    /// # }
+    /// ```
+    pub fn list_user_trades_stream(
+        &self,
+        query: ListUserTradesQuery,
+    ) -> impl Stream<Item = Result<UserTrade, Error>> {
+        paginate::user_trades(self.http.clone(), query)
+    }
+
+    /// Stream every trade for a currency pair between `from` and `to`, oldest first.
+    ///
+    /// Unlike `list_trades`, this is not limited to 100 trades: pages are fetched until `to`
+    /// is reached. Trades repeated across pages are skipped and any jump in sequence numbers
+    /// is reported as an `Error::SequenceGap` item, so a complete tape can be verified.
+    /// Luno only serves trades from the last 24 hours.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    /// use futures_util::StreamExt;
+    /// use luno_rs::{CurrencyPair, LunoClient};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let to = Utc::now();
+    /// let from = to - Duration::hours(23);
+    /// let mut trades = Box::pin(client.trade_history(CurrencyPair::XBTNGN, from, to));
+    /// while let Some(trade) = trades.next().await {
+    ///     match trade {
+    ///         Ok(trade) => println!("{} -> {} @ {}", trade.sequence, trade.volume, trade.price),
+    ///         Err(e) => eprintln!("{}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn trade_history(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> impl Stream<Item = Result<Trade, Error>> {
+        paginate::trades(self.http.clone(), currency_pair, from, to)
+    }
 }
@@ -1,3 +1,4 @@
+#[derive(Clone)]
 pub struct Credential {
     pub key_id: String,
     pub key_secret: String,
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// Account holding a single currency on Luno profile.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Account {
+    /// Same as `AccountBalance.account_id`
+    pub id: String,
+    pub name: String,
+    pub currency: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateAccountRequest {
+    currency: String,
+    name: String,
+}
+
+impl CreateAccountRequest {
+    pub fn new(currency: &str, name: &str) -> Self {
+        CreateAccountRequest {
+            currency: currency.into(),
+            name: name.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateAccountNameRequest {
+    name: String,
+}
+
+impl UpdateAccountNameRequest {
+    pub fn new(name: &str) -> Self {
+        UpdateAccountNameRequest { name: name.into() }
+    }
+}
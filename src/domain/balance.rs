@@ -1,12 +1,16 @@
+use super::{add_amount, Amount};
+use crate::domain::Asset;
+use crate::error::Error;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct AccountBalance {
     pub account_id: String,
     pub asset: String,
-    pub balance: String,
-    pub reserved: String,
-    pub unconfirmed: String,
+    pub balance: Amount,
+    pub reserved: Amount,
+    pub unconfirmed: Amount,
 }
 
 #[derive(Deserialize)]
@@ -14,3 +18,82 @@ pub struct ListBalancesResponse {
     #[serde(rename = "balance")]
     pub balances: Vec<AccountBalance>,
 }
+
+/// Totals of the balances of all accounts holding the same asset, summed exactly.
+#[derive(Debug, PartialEq)]
+pub struct BalanceTotal {
+    pub balance: Amount,
+    pub reserved: Amount,
+    pub unconfirmed: Amount,
+}
+
+/// Account balances indexed by asset.
+///
+/// # Examples
+///
+/// ```
+/// use luno_rs::{AccountBalance, Asset, BalanceSet};
+/// let balances: Vec<AccountBalance> = serde_json::from_str(r#"[
+///     {"account_id": "1", "asset": "XBT", "balance": "0.5", "reserved": "0.1", "unconfirmed": "0"},
+///     {"account_id": "2", "asset": "XBT", "balance": "0.25", "reserved": "0", "unconfirmed": "0.1"}
+/// ]"#).unwrap();
+/// let balance_set = BalanceSet::new(balances);
+/// assert_eq!(balance_set.accounts(Asset::XBT).len(), 2);
+/// assert_eq!(balance_set.total(Asset::XBT).unwrap().balance.to_string(), "0.75");
+/// assert_eq!(balance_set.total(Asset::NGN).unwrap().balance.to_string(), "0");
+/// ```
+#[derive(Debug, Default)]
+pub struct BalanceSet {
+    balances: HashMap<String, Vec<AccountBalance>>,
+}
+
+impl BalanceSet {
+    /// Create a new BalanceSet from the balances of individual accounts
+    pub fn new(balances: Vec<AccountBalance>) -> Self {
+        let mut balance_set = BalanceSet::default();
+        for balance in balances {
+            balance_set
+                .balances
+                .entry(balance.asset.clone())
+                .or_insert_with(Vec::new)
+                .push(balance);
+        }
+        balance_set
+    }
+
+    /// Balances of all accounts holding an asset
+    pub fn accounts(&self, asset: Asset) -> &[AccountBalance] {
+        self.balances
+            .get(&asset.to_string())
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Sum of the balances of all accounts holding an asset. Assets without any account
+    /// have a zero total
+    pub fn total(&self, asset: Asset) -> Result<BalanceTotal, Error> {
+        let zero = || "0".parse::<Amount>().expect("zero is a valid amount");
+        let mut total = BalanceTotal {
+            balance: zero(),
+            reserved: zero(),
+            unconfirmed: zero(),
+        };
+        for account in self.accounts(asset) {
+            total.balance = add_amount(&total.balance, &account.balance)?;
+            total.reserved = add_amount(&total.reserved, &account.reserved)?;
+            total.unconfirmed = add_amount(&total.unconfirmed, &account.unconfirmed)?;
+        }
+        Ok(total)
+    }
+
+    /// Assets held in at least one account
+    pub fn assets(&self) -> impl Iterator<Item = &str> {
+        self.balances.keys().map(String::as_str)
+    }
+}
+
+impl From<Vec<AccountBalance>> for BalanceSet {
+    fn from(balances: Vec<AccountBalance>) -> Self {
+        BalanceSet::new(balances)
+    }
+}
@@ -0,0 +1,158 @@
+use crate::domain::{Amount, CurrencyPair};
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt, time::Duration};
+
+/// Duration of a candlestick supported by Luno.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CandleDuration {
+    OneMinute,
+    FiveMinutes,
+    FifteenMinutes,
+    ThirtyMinutes,
+    OneHour,
+    ThreeHours,
+    FourHours,
+    EightHours,
+    OneDay,
+    ThreeDays,
+    SevenDays,
+}
+
+impl CandleDuration {
+    /// Length of the candlestick in seconds
+    pub fn as_secs(&self) -> u64 {
+        match self {
+            CandleDuration::OneMinute => 60,
+            CandleDuration::FiveMinutes => 300,
+            CandleDuration::FifteenMinutes => 900,
+            CandleDuration::ThirtyMinutes => 1800,
+            CandleDuration::OneHour => 3600,
+            CandleDuration::ThreeHours => 10800,
+            CandleDuration::FourHours => 14400,
+            CandleDuration::EightHours => 28800,
+            CandleDuration::OneDay => 86400,
+            CandleDuration::ThreeDays => 259200,
+            CandleDuration::SevenDays => 604800,
+        }
+    }
+}
+
+impl From<CandleDuration> for Duration {
+    fn from(duration: CandleDuration) -> Self {
+        Duration::from_secs(duration.as_secs())
+    }
+}
+
+impl fmt::Display for CandleDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CandleDuration::OneMinute => write!(f, "1m"),
+            CandleDuration::FiveMinutes => write!(f, "5m"),
+            CandleDuration::FifteenMinutes => write!(f, "15m"),
+            CandleDuration::ThirtyMinutes => write!(f, "30m"),
+            CandleDuration::OneHour => write!(f, "1h"),
+            CandleDuration::ThreeHours => write!(f, "3h"),
+            CandleDuration::FourHours => write!(f, "4h"),
+            CandleDuration::EightHours => write!(f, "8h"),
+            CandleDuration::OneDay => write!(f, "24h"),
+            CandleDuration::ThreeDays => write!(f, "3d"),
+            CandleDuration::SevenDays => write!(f, "7d"),
+        }
+    }
+}
+
+/// Open, high, low and close prices and traded volume over one candlestick duration.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Candle {
+    /// Start of the candlestick
+    #[serde(with = "ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub open: Amount,
+    pub high: Amount,
+    pub low: Amount,
+    pub close: Amount,
+    pub volume: Amount,
+}
+
+#[derive(Deserialize)]
+pub struct GetCandlesResponse {
+    pub candles: Vec<Candle>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetCandlesQuery {
+    pair: CurrencyPair,
+    since: i64,
+    duration: u64,
+}
+
+impl GetCandlesQuery {
+    pub fn new(
+        currency_pair: CurrencyPair,
+        since: DateTime<Utc>,
+        duration: CandleDuration,
+    ) -> Self {
+        GetCandlesQuery {
+            pair: currency_pair,
+            since: since.timestamp_millis(),
+            duration: duration.as_secs(),
+        }
+    }
+
+    pub(crate) fn since_millis(&self) -> i64 {
+        self.since
+    }
+
+    pub(crate) fn set_since_millis(&mut self, since: i64) {
+        self.since = since;
+    }
+
+    pub(crate) fn duration_millis(&self) -> i64 {
+        self.duration as i64 * 1000
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CandleDuration, GetCandlesQuery, GetCandlesResponse};
+    use crate::domain::CurrencyPair;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn parses_json_correctly() {
+        let data = r#"
+        {
+            "candles": [
+                {
+                    "timestamp": 1609459200000,
+                    "open": "11000000",
+                    "close": "11100000",
+                    "high": "11200000",
+                    "low": "10900000",
+                    "volume": "12.5"
+                }
+            ],
+            "duration": 3600,
+            "pair": "XBTNGN"
+        }
+        "#;
+        let response: GetCandlesResponse = serde_json::from_str(data).unwrap();
+        assert_eq!(
+            response.candles[0].timestamp.to_string(),
+            "2021-01-01 00:00:00 UTC"
+        );
+        assert_eq!(response.candles[0].high.to_string(), "11200000");
+    }
+
+    #[test]
+    fn serializes_duration_in_seconds() {
+        let since = Utc.timestamp_millis_opt(1609459200000).unwrap();
+        let query = GetCandlesQuery::new(CurrencyPair::XBTNGN, since, CandleDuration::FourHours);
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            serde_json::json!({"pair": "XBTNGN", "since": 1609459200000i64, "duration": 14400})
+        );
+    }
+}
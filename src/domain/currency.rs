@@ -1,4 +1,5 @@
 use crate::error::Error;
+use serde::Serialize;
 use std::{fmt, str};
 /// Currency pairs available on Luno.
 ///
@@ -19,7 +20,7 @@ use std::{fmt, str};
 /// # Error
 ///
 /// Error::InvalidCurrencyPair is returned if string cannot to converted to a valid currency pair.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
 pub enum CurrencyPair {
     BCHXBT,
     XBTAUD,
@@ -27,6 +28,7 @@ pub enum CurrencyPair {
     XBTGBP,
     XBTIDR,
     XBTMYR,
+    #[default]
     XBTNGN,
     XBTSGD,
     XBTUGX,
@@ -121,15 +123,89 @@ impl str::FromStr for CurrencyPair {
     }
 }
 
-impl Default for CurrencyPair {
-    fn default() -> Self {
-        CurrencyPair::XBTNGN
+/// Assets (currencies) held in accounts on Luno.
+///
+/// # Examples
+///
+/// ```
+/// use luno_rs::Asset;
+/// let asset: Asset = "XBT".parse().unwrap();
+/// assert_eq!(asset, Asset::XBT);
+/// assert_eq!(Asset::NGN.to_string(), "NGN");
+/// ```
+///
+/// # Error
+///
+/// Error::InvalidAsset is returned if string cannot to converted to a valid asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum Asset {
+    AUD,
+    BCH,
+    ETH,
+    EUR,
+    GBP,
+    IDR,
+    LTC,
+    MYR,
+    NGN,
+    SGD,
+    UGX,
+    XBT,
+    XRP,
+    ZAR,
+    ZMW,
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Asset::AUD => write!(f, "AUD"),
+            Asset::BCH => write!(f, "BCH"),
+            Asset::ETH => write!(f, "ETH"),
+            Asset::EUR => write!(f, "EUR"),
+            Asset::GBP => write!(f, "GBP"),
+            Asset::IDR => write!(f, "IDR"),
+            Asset::LTC => write!(f, "LTC"),
+            Asset::MYR => write!(f, "MYR"),
+            Asset::NGN => write!(f, "NGN"),
+            Asset::SGD => write!(f, "SGD"),
+            Asset::UGX => write!(f, "UGX"),
+            Asset::XBT => write!(f, "XBT"),
+            Asset::XRP => write!(f, "XRP"),
+            Asset::ZAR => write!(f, "ZAR"),
+            Asset::ZMW => write!(f, "ZMW"),
+        }
+    }
+}
+
+impl str::FromStr for Asset {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AUD" => Ok(Asset::AUD),
+            "BCH" => Ok(Asset::BCH),
+            "ETH" => Ok(Asset::ETH),
+            "EUR" => Ok(Asset::EUR),
+            "GBP" => Ok(Asset::GBP),
+            "IDR" => Ok(Asset::IDR),
+            "LTC" => Ok(Asset::LTC),
+            "MYR" => Ok(Asset::MYR),
+            "NGN" => Ok(Asset::NGN),
+            "SGD" => Ok(Asset::SGD),
+            "UGX" => Ok(Asset::UGX),
+            "XBT" => Ok(Asset::XBT),
+            "XRP" => Ok(Asset::XRP),
+            "ZAR" => Ok(Asset::ZAR),
+            "ZMW" => Ok(Asset::ZMW),
+            _ => Err(Error::InvalidAsset(s.to_string())),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::CurrencyPair;
+    use super::{Asset, CurrencyPair};
 
     #[test]
     fn test_currency_pair_to_valid_conversion() {
@@ -152,4 +228,11 @@ mod tests {
         let currency_pair = CurrencyPair::default();
         assert_eq!(currency_pair, CurrencyPair::XBTNGN);
     }
+
+    #[test]
+    fn test_string_to_valid_asset_conversion() {
+        let asset: Asset = "NGN".parse().unwrap();
+        assert_eq!(Asset::NGN, asset);
+        assert!("LUNO".parse::<Asset>().is_err());
+    }
 }
@@ -0,0 +1,231 @@
+use super::{add_amount, compare_amounts, multiply_amount, subtract_amount, Amount};
+use crate::domain::{OrderBook, OrderBookEntry, OrderType};
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Fees charged on the Luno profile for a currency pair, as fractions of the traded amount.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FeeInfo {
+    pub maker_fee: Amount,
+    pub taker_fee: Amount,
+    /// Volume traded in the last 30 days, which determines the fee tier
+    pub thirty_day_volume: Amount,
+}
+
+/// Whether an order adds liquidity to the order book, removes it, or both.
+#[derive(Debug, PartialEq)]
+pub enum Liquidity {
+    Maker,
+    Taker,
+    Mixed,
+}
+
+/// Estimated outcome of placing a limit order against an order book. Volumes and fees
+/// are exact and carry every decimal place of the calculation.
+#[derive(Debug)]
+pub struct FeeEstimate {
+    pub liquidity: Liquidity,
+    /// Volume expected to rest on the order book
+    pub maker_volume: Amount,
+    /// Volume expected to trade immediately against the order book
+    pub taker_volume: Amount,
+    /// Fee charged in the base currency, which is what a BID receives
+    pub fee_base: Amount,
+    /// Fee charged in the counter currency, which is what an ASK receives
+    pub fee_counter: Amount,
+}
+
+/// Estimates the fees of a limit order before it is placed.
+///
+/// # Examples
+///
+/// ```
+/// use luno_rs::{FeeEstimator, FeeInfo, Liquidity, OrderBook, OrderType};
+/// let fee_info: FeeInfo = serde_json::from_str(
+///     r#"{"maker_fee": "0.0000", "taker_fee": "0.0010", "thirty_day_volume": "0.5"}"#,
+/// ).unwrap();
+/// let order_book: OrderBook = serde_json::from_str(
+///     r#"{"timestamp": 1609241817077,
+///         "asks": [{"price": "12580000", "volume": "0.5"}],
+///         "bids": [{"price": "12570000", "volume": "0.5"}]}"#,
+/// ).unwrap();
+/// let estimator = FeeEstimator::new(&fee_info).unwrap();
+/// let estimate = estimator
+///     .estimate(&order_book, OrderType::BID, "12580000", "0.1")
+///     .unwrap();
+/// assert_eq!(estimate.liquidity, Liquidity::Taker);
+/// ```
+#[derive(Debug)]
+pub struct FeeEstimator {
+    maker_fee: Amount,
+    taker_fee: Amount,
+}
+
+impl FeeEstimator {
+    /// Create a new FeeEstimator from the fees of the Luno profile
+    pub fn new(fee_info: &FeeInfo) -> Result<Self, Error> {
+        Ok(FeeEstimator {
+            maker_fee: to_amount(&fee_info.maker_fee)?,
+            taker_fee: to_amount(&fee_info.taker_fee)?,
+        })
+    }
+
+    /// Estimate the fees of a limit order of `volume` at `price` given the current order book
+    pub fn estimate<A: fmt::Display>(
+        &self,
+        order_book: &OrderBook,
+        order_type: OrderType,
+        price: A,
+        volume: A,
+    ) -> Result<FeeEstimate, Error> {
+        let price = to_amount(&price)?;
+        let volume = to_amount(&volume)?;
+        let zero = || "0".parse::<Amount>().expect("zero is a valid amount");
+        // Entries priced beyond the limit price do not cross it
+        let mut crossing = match order_type {
+            OrderType::BID => crossing_entries(&order_book.asks, &price, Ordering::Greater)?,
+            OrderType::ASK => crossing_entries(&order_book.bids, &price, Ordering::Less)?,
+        };
+        let by_price = |a: &&OrderBookEntry, b: &&OrderBookEntry| {
+            compare_amounts(&a.price, &b.price).unwrap_or(Ordering::Equal)
+        };
+        match order_type {
+            OrderType::BID => crossing.sort_by(by_price),
+            OrderType::ASK => crossing.sort_by(|a, b| by_price(b, a)),
+        }
+
+        let mut taker_volume = zero();
+        let mut taker_counter = zero();
+        for entry in crossing {
+            let unfilled = subtract_amount(&volume, &taker_volume)?;
+            if compare_amounts(&unfilled, &zero())? != Ordering::Greater {
+                break;
+            }
+            let filled = match compare_amounts(&entry.volume, &unfilled)? {
+                Ordering::Less => &entry.volume,
+                _ => &unfilled,
+            };
+            taker_counter = add_amount(&taker_counter, &multiply_amount(filled, &entry.price)?)?;
+            taker_volume = add_amount(&taker_volume, filled)?;
+        }
+        let maker_volume = subtract_amount(&volume, &taker_volume)?;
+        let maker_counter = multiply_amount(&maker_volume, &price)?;
+
+        let is_zero = |amount: &Amount| -> Result<bool, Error> {
+            Ok(compare_amounts(amount, &zero())? == Ordering::Equal)
+        };
+        let liquidity = if is_zero(&taker_volume)? {
+            Liquidity::Maker
+        } else if is_zero(&maker_volume)? {
+            Liquidity::Taker
+        } else {
+            Liquidity::Mixed
+        };
+        let fee = |maker: &Amount, taker: &Amount| -> Result<Amount, Error> {
+            add_amount(
+                &multiply_amount(maker, &self.maker_fee)?,
+                &multiply_amount(taker, &self.taker_fee)?,
+            )
+        };
+        let (fee_base, fee_counter) = match order_type {
+            OrderType::BID => (fee(&maker_volume, &taker_volume)?, zero()),
+            OrderType::ASK => (zero(), fee(&maker_counter, &taker_counter)?),
+        };
+        Ok(FeeEstimate {
+            liquidity,
+            maker_volume,
+            taker_volume,
+            fee_base,
+            fee_counter,
+        })
+    }
+}
+
+fn to_amount<A: fmt::Display>(amount: &A) -> Result<Amount, Error> {
+    let amount = amount.to_string();
+    amount.parse().map_err(|_| Error::InvalidAmount(amount))
+}
+
+/// Entries of one side of the order book that a limit order at `price` trades against,
+/// which are those not priced `beyond` it.
+fn crossing_entries<'a>(
+    entries: &'a [OrderBookEntry],
+    price: &Amount,
+    beyond: Ordering,
+) -> Result<Vec<&'a OrderBookEntry>, Error> {
+    let mut crossing = Vec::new();
+    for entry in entries {
+        if compare_amounts(&entry.price, price)? != beyond {
+            crossing.push(entry);
+        }
+    }
+    Ok(crossing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FeeEstimator, FeeInfo, Liquidity};
+    use crate::domain::{compare_amounts, Amount, OrderBook, OrderType};
+    use std::cmp::Ordering;
+
+    fn exactly(amount: &Amount, expected: &str) -> bool {
+        compare_amounts(amount, &expected.parse().unwrap()).unwrap() == Ordering::Equal
+    }
+
+    fn estimator() -> FeeEstimator {
+        let fee_info = FeeInfo {
+            maker_fee: "0.001".parse().unwrap(),
+            taker_fee: "0.002".parse().unwrap(),
+            thirty_day_volume: "1.2".parse().unwrap(),
+        };
+        FeeEstimator::new(&fee_info).unwrap()
+    }
+
+    fn order_book() -> OrderBook {
+        let data = r#"
+        {
+            "timestamp": 1609241817077,
+            "asks": [
+                {"price": "101", "volume": "1"},
+                {"price": "100", "volume": "1"}
+            ],
+            "bids": [
+                {"price": "99", "volume": "1"},
+                {"price": "98", "volume": "1"}
+            ]
+        }
+        "#;
+        serde_json::from_str(data).unwrap()
+    }
+
+    #[test]
+    fn bid_below_best_ask_is_maker() {
+        let estimate = estimator()
+            .estimate(&order_book(), OrderType::BID, "99.5", "2")
+            .unwrap();
+        assert_eq!(estimate.liquidity, Liquidity::Maker);
+        assert!(exactly(&estimate.fee_base, "0.002"));
+        assert!(exactly(&estimate.fee_counter, "0"));
+    }
+
+    #[test]
+    fn ask_through_bids_is_partly_taker() {
+        let estimate = estimator()
+            .estimate(&order_book(), OrderType::ASK, "98", "3")
+            .unwrap();
+        assert_eq!(estimate.liquidity, Liquidity::Mixed);
+        assert!(exactly(&estimate.taker_volume, "2"));
+        assert!(exactly(&estimate.maker_volume, "1"));
+        // (99 + 98) * 0.002 taker + 98 * 0.001 maker
+        assert!(exactly(&estimate.fee_counter, "0.492"));
+    }
+
+    #[test]
+    fn invalid_amount_is_rejected() {
+        assert!(estimator()
+            .estimate(&order_book(), OrderType::BID, "abc", "1")
+            .is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use crate::domain::{Amount, Asset};
+use serde::{Deserialize, Serialize};
+
+/// Address that receives funds into an account on Luno profile.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FundingAddress {
+    pub account_id: String,
+    pub asset: String,
+    pub address: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// URI of the address for encoding in a QR code
+    pub qr_code_uri: String,
+    /// Total amount received on this address, including unconfirmed receipts
+    pub total_received: Amount,
+    /// Amount received on this address that is not yet confirmed
+    pub total_unconfirmed: Amount,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetFundingAddressQuery<'a> {
+    asset: Asset,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<&'a str>,
+}
+
+impl<'a> GetFundingAddressQuery<'a> {
+    pub fn new(asset: Asset, address: Option<&'a str>) -> Self {
+        GetFundingAddressQuery { asset, address }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateFundingAddressRequest<'a> {
+    asset: Asset,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_id: Option<&'a str>,
+}
+
+impl<'a> CreateFundingAddressRequest<'a> {
+    pub fn new(asset: Asset, name: Option<&'a str>, account_id: Option<&'a str>) -> Self {
+        CreateFundingAddressRequest {
+            asset,
+            name,
+            account_id,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ListFundingAddressesResponse {
+    pub addresses: Option<Vec<FundingAddress>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FundingAddress;
+
+    #[test]
+    fn parses_json_correctly() {
+        let data = r#"
+        {
+            "account_id": "12345678910",
+            "address": "B1tC0InExAMPL3fundIN6AdDreS5t0Use",
+            "asset": "XBT",
+            "assigned_at": 1609748321481,
+            "name": "customer-42",
+            "qr_code_uri": "bitcoin:B1tC0InExAMPL3fundIN6AdDreS5t0Use",
+            "receive_fee": "0.00",
+            "total_received": "1.234567",
+            "total_unconfirmed": "0.00"
+        }
+        "#;
+        let address: FundingAddress = serde_json::from_str(data).unwrap();
+        assert_eq!(address.name.as_deref(), Some("customer-42"));
+        assert_eq!(address.total_received.to_string(), "1.234567");
+    }
+}
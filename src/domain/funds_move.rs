@@ -0,0 +1,192 @@
+use super::Amount;
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Status of a move of funds between accounts.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MoveStatus {
+    Created,
+    Moving,
+    Successful,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Move of funds between two accounts of the same currency on Luno profile.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FundsMove {
+    pub id: String,
+    #[serde(default)]
+    pub client_move_id: Option<String>,
+    pub debit_account_id: String,
+    pub credit_account_id: String,
+    pub amount: Amount,
+    pub status: MoveStatus,
+    #[serde(with = "ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "ts_milliseconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Identifies a move either by the id assigned by Luno or by the client move id supplied
+/// when it was requested.
+#[derive(Debug)]
+pub enum MoveReference {
+    MoveId(String),
+    ClientMoveId(String),
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetMoveQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_move_id: Option<String>,
+}
+
+impl From<MoveReference> for GetMoveQuery {
+    fn from(reference: MoveReference) -> Self {
+        match reference {
+            MoveReference::MoveId(id) => GetMoveQuery {
+                id: Some(id),
+                ..Default::default()
+            },
+            MoveReference::ClientMoveId(client_move_id) => GetMoveQuery {
+                client_move_id: Some(client_move_id),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Parameters of a move to be requested with `LunoClient::move_funds`.
+///
+/// Use `MoveFundsRequestBuilder` to create one.
+#[derive(Debug, Serialize)]
+pub struct MoveFundsRequest {
+    amount: String,
+    debit_account_id: String,
+    credit_account_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_move_id: Option<String>,
+}
+
+impl MoveFundsRequest {
+    pub(crate) fn client_move_id(&self) -> Option<&str> {
+        self.client_move_id.as_deref()
+    }
+}
+
+pub struct MoveFundsRequestBuilder {
+    request: MoveFundsRequest,
+}
+
+impl MoveFundsRequestBuilder {
+    /// Create a new MoveFundsRequestBuilder that moves `amount` from the debit account to
+    /// the credit account
+    pub fn new<A: fmt::Display, T: AsRef<str>>(
+        amount: A,
+        debit_account_id: T,
+        credit_account_id: T,
+    ) -> Self {
+        MoveFundsRequestBuilder {
+            request: MoveFundsRequest {
+                amount: amount.to_string(),
+                debit_account_id: debit_account_id.as_ref().into(),
+                credit_account_id: credit_account_id.as_ref().into(),
+                client_move_id: None,
+            },
+        }
+    }
+
+    /// Add a client move id which must be unique across all moves on the Luno profile.
+    /// Requesting the same client move id again does not move the funds twice
+    pub fn with_client_move_id<T: AsRef<str>>(mut self, client_move_id: T) -> Self {
+        self.request.client_move_id = Some(client_move_id.as_ref().into());
+        self
+    }
+
+    /// Build MoveFundsRequestBuilder into a MoveFundsRequest
+    pub fn build(self) -> MoveFundsRequest {
+        self.request
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MoveFundsResponse {
+    pub id: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ListMovesQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+}
+
+impl ListMovesQuery {
+    pub fn new(before: Option<DateTime<Utc>>, limit: Option<u32>) -> Self {
+        ListMovesQuery {
+            before: before.map(|before| before.timestamp_millis()),
+            limit,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ListMovesResponse {
+    pub moves: Option<Vec<FundsMove>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GetMoveQuery, ListMovesResponse, MoveReference, MoveStatus};
+    use surf::http::{Method, Request, Url};
+
+    fn encode(query: GetMoveQuery) -> String {
+        let url = Url::parse("https://api.luno.com/api/exchange/1/move").unwrap();
+        let mut request = Request::new(Method::Get, url);
+        request.set_query(&query).unwrap();
+        request.url().query().unwrap_or_default().to_string()
+    }
+
+    #[test]
+    fn encodes_get_move_query() {
+        let by_id = GetMoveQuery::from(MoveReference::MoveId("18563829047".into()));
+        assert_eq!(encode(by_id), "id=18563829047");
+        let by_client_id = GetMoveQuery::from(MoveReference::ClientMoveId("rebalance-7".into()));
+        assert_eq!(encode(by_client_id), "client_move_id=rebalance-7");
+    }
+
+    #[test]
+    fn parses_json_correctly() {
+        let data = r#"
+        {
+            "moves": [
+                {
+                    "id": "18563829047",
+                    "client_move_id": "rebalance-2021-01-04",
+                    "debit_account_id": "12345678910",
+                    "credit_account_id": "12345678911",
+                    "amount": "10000.00",
+                    "status": "SUCCESSFUL",
+                    "created_at": 1609748321481,
+                    "updated_at": 1609748321523
+                }
+            ]
+        }
+        "#;
+        let response: ListMovesResponse = serde_json::from_str(data).unwrap();
+        let moves = response.moves.unwrap();
+        assert_eq!(moves[0].status, MoveStatus::Successful);
+        assert_eq!(
+            moves[0].client_move_id.as_deref(),
+            Some("rebalance-2021-01-04")
+        );
+    }
+}
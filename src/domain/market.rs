@@ -1,13 +1,198 @@
-use serde::Deserialize;
+use super::{compare_amounts, Amount};
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
 
-#[derive(Deserialize)]
+/// Trading status of a market.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TradingStatus {
+    /// Only post-only limit orders are accepted
+    PostOnly,
+    /// All order types are accepted
+    Active,
+    /// Trading is halted
+    Suspended,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Trading limits and precision of a market on Luno.
+#[derive(Debug, Deserialize, Serialize)]
 pub struct MarketsInfo {
+    pub market_id: String,
+    pub trading_status: TradingStatus,
     pub base_currency: String,
     pub counter_currency: String,
-    pub fee_scale: String,
+    pub min_volume: Amount,
+    pub max_volume: Amount,
+    /// Maximum number of decimal places in an order volume
+    pub volume_scale: u32,
+    pub min_price: Amount,
+    pub max_price: Amount,
+    /// Maximum number of decimal places in an order price
+    pub price_scale: u32,
+    /// Number of decimal places in fees
+    pub fee_scale: u32,
+}
+
+impl MarketsInfo {
+    /// Check a limit order of `volume` at `price` against the limits of this market
+    /// before sending it to Luno. While the market is `PostOnly` only post-only orders
+    /// are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luno_rs::MarketsInfo;
+    /// let market: MarketsInfo = serde_json::from_str(r#"{
+    ///     "market_id": "XBTNGN", "trading_status": "ACTIVE",
+    ///     "base_currency": "XBT", "counter_currency": "NGN",
+    ///     "min_volume": "0.0005", "max_volume": "5.0", "volume_scale": 6,
+    ///     "min_price": "100", "max_price": "100000000", "price_scale": 0, "fee_scale": 8
+    /// }"#).unwrap();
+    /// assert!(market.validate_order("12000000", "0.001", false).is_ok());
+    /// assert!(market.validate_order("12000000.5", "0.001", false).is_err());
+    /// ```
+    pub fn validate_order<A: fmt::Display>(
+        &self,
+        price: A,
+        volume: A,
+        post_only: bool,
+    ) -> Result<(), Error> {
+        let (price, volume) = (price.to_string(), volume.to_string());
+        match self.trading_status {
+            TradingStatus::Active => {}
+            TradingStatus::PostOnly if post_only => {}
+            TradingStatus::PostOnly => {
+                return Err(Error::InvalidOrder(format!(
+                    "market {} only accepts post-only orders",
+                    self.market_id
+                )));
+            }
+            _ => {
+                return Err(Error::InvalidOrder(format!(
+                    "market {} is not trading",
+                    self.market_id
+                )));
+            }
+        }
+        check_range("volume", &volume, &self.min_volume, &self.max_volume)?;
+        check_scale("volume", &volume, self.volume_scale)?;
+        check_range("price", &price, &self.min_price, &self.max_price)?;
+        check_scale("price", &price, self.price_scale)
+    }
+}
+
+fn check_range(name: &str, value: &str, min: &Amount, max: &Amount) -> Result<(), Error> {
+    let parsed: Amount = value
+        .parse()
+        .map_err(|_| Error::InvalidAmount(value.to_string()))?;
+    if compare_amounts(&parsed, min)? == Ordering::Less
+        || compare_amounts(&parsed, max)? == Ordering::Greater
+    {
+        return Err(Error::InvalidOrder(format!(
+            "{} {} is outside of {} to {}",
+            name, value, min, max
+        )));
+    }
+    Ok(())
+}
+
+fn check_scale(name: &str, value: &str, scale: u32) -> Result<(), Error> {
+    let decimals = value
+        .split_once('.')
+        .map(|(_, fraction)| fraction.trim_end_matches('0').len())
+        .unwrap_or(0);
+    if decimals > scale as usize {
+        return Err(Error::InvalidOrder(format!(
+            "{} {} has more than {} decimal places",
+            name, value, scale
+        )));
+    }
+    Ok(())
 }
 
 #[derive(Deserialize)]
 pub struct GetMarketsInfoResponse {
     pub markets: Vec<MarketsInfo>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{GetMarketsInfoResponse, MarketsInfo, TradingStatus};
+
+    #[test]
+    fn parses_json_correctly() {
+        let data = r#"
+        {
+            "markets": [
+                {
+                    "market_id": "XBTNGN",
+                    "trading_status": "ACTIVE",
+                    "base_currency": "XBT",
+                    "counter_currency": "NGN",
+                    "min_volume": "0.0005",
+                    "max_volume": "5.0",
+                    "volume_scale": 6,
+                    "min_price": "100",
+                    "max_price": "100000000",
+                    "price_scale": 0,
+                    "fee_scale": 8
+                },
+                {
+                    "market_id": "XRPNGN",
+                    "trading_status": "DISABLED",
+                    "base_currency": "XRP",
+                    "counter_currency": "NGN",
+                    "min_volume": "1",
+                    "max_volume": "100000",
+                    "volume_scale": 0,
+                    "min_price": "1",
+                    "max_price": "10000",
+                    "price_scale": 2,
+                    "fee_scale": 8
+                }
+            ]
+        }
+        "#;
+        let response: GetMarketsInfoResponse = serde_json::from_str(data).unwrap();
+        assert_eq!(response.markets[0].trading_status, TradingStatus::Active);
+        assert_eq!(response.markets[0].volume_scale, 6);
+        assert_eq!(response.markets[1].trading_status, TradingStatus::Unknown);
+        assert!(response.markets[1]
+            .validate_order("200", "10", false)
+            .is_err());
+    }
+
+    fn market(trading_status: &str) -> MarketsInfo {
+        let data = format!(
+            r#"{{"market_id": "XBTNGN", "trading_status": "{}",
+                "base_currency": "XBT", "counter_currency": "NGN",
+                "min_volume": "0.0005", "max_volume": "5.0", "volume_scale": 30,
+                "min_price": "100", "max_price": "100000000", "price_scale": 0, "fee_scale": 8}}"#,
+            trading_status
+        );
+        serde_json::from_str(&data).unwrap()
+    }
+
+    #[test]
+    fn post_only_market_rejects_other_orders() {
+        let market = market("POST_ONLY");
+        assert!(market.validate_order("12000000", "0.001", true).is_ok());
+        assert!(market.validate_order("12000000", "0.001", false).is_err());
+    }
+
+    #[test]
+    fn compares_limits_exactly() {
+        let market = market("ACTIVE");
+        assert!(market.validate_order("100", "0.0005", false).is_ok());
+        assert!(market
+            .validate_order("100", "0.0004999999999999999999", false)
+            .is_err());
+        assert!(market
+            .validate_order("100", "5.0000000000000000001", false)
+            .is_err());
+    }
+}
@@ -1,17 +1,248 @@
+mod account;
 mod balance;
+mod candle;
 mod currency;
+mod fee;
+mod funding;
+mod funds_move;
 mod market;
 mod order;
+mod order_request;
+mod send;
 mod ticker;
 mod trade;
-pub use balance::{AccountBalance, ListBalancesResponse};
-pub use currency::CurrencyPair;
-pub use market::{GetMarketsInfoResponse, MarketsInfo};
-pub use order::{ListOrdersResponse, Order, OrderBook, OrderBookEntry, OrderType};
-use serde::{self, de};
+mod transaction;
+mod transfer;
+mod withdrawal;
+pub use account::{Account, CreateAccountRequest, UpdateAccountNameRequest};
+pub use balance::{AccountBalance, BalanceSet, BalanceTotal, ListBalancesResponse};
+pub use candle::{Candle, CandleDuration, GetCandlesQuery, GetCandlesResponse};
+pub use currency::{Asset, CurrencyPair};
+pub use fee::{FeeEstimate, FeeEstimator, FeeInfo, Liquidity};
+pub use funding::{
+    CreateFundingAddressRequest, FundingAddress, GetFundingAddressQuery,
+    ListFundingAddressesResponse,
+};
+pub use funds_move::{
+    FundsMove, GetMoveQuery, ListMovesQuery, ListMovesResponse, MoveFundsRequest,
+    MoveFundsRequestBuilder, MoveFundsResponse, MoveReference, MoveStatus,
+};
+pub use market::{GetMarketsInfoResponse, MarketsInfo, TradingStatus};
+pub use order::{
+    GetOrderQuery, ListOrdersResponse, Order, OrderBook, OrderBookEntry, OrderDetails, OrderKind,
+    OrderReference, OrderState, OrderStatus, OrderType, StopDirection,
+};
+pub use order_request::{
+    ListOrdersQuery, PostMarketOrderRequest, PostMarketOrderRequestBuilder, PostOrderRequest,
+    PostOrderRequestBuilder, PostOrderResponse, StopOrderRequest, TimeInForce,
+};
+pub use send::{SendFee, SendFeeQuery, SendRequest, SendRequestBuilder, SendResponse};
+use serde::{self, de, Deserialize};
+use std::cmp::Ordering;
 use std::fmt;
 pub use ticker::{ListTickersResponse, Ticker};
-pub use trade::{ListTradesResponse, Trade};
+pub use trade::{
+    ListTradesResponse, ListUserTradesQuery, ListUserTradesResponse, Trade, UserTrade,
+};
+pub use transaction::{
+    ListPendingTransactionsResponse, ListTransactionsQuery, ListTransactionsResponse, Transaction,
+    TransactionKind,
+};
+pub use transfer::{
+    Beneficiary, ListBeneficiariesResponse, ListTransfersQuery, ListTransfersResponse, Transfer,
+};
+pub use withdrawal::{
+    CreateWithdrawalRequest, CreateWithdrawalRequestBuilder, ListWithdrawalsQuery,
+    ListWithdrawalsResponse, Withdrawal, WithdrawalStatus,
+};
+
+/// Response of the endpoints that only report whether the request succeeded.
+#[derive(Deserialize)]
+pub struct SuccessResponse {
+    pub success: bool,
+}
+
+/// Type of prices, volumes and amounts: the decimal string sent by Luno, or an exact
+/// `rust_decimal::Decimal` when the `decimal` feature is enabled. Both serialize back
+/// to Luno's string format.
+#[cfg(not(feature = "decimal"))]
+pub type Amount = String;
+/// Type of prices, volumes and amounts: the decimal string sent by Luno, or an exact
+/// `rust_decimal::Decimal` when the `decimal` feature is enabled. Both serialize back
+/// to Luno's string format.
+#[cfg(feature = "decimal")]
+pub type Amount = rust_decimal::Decimal;
+
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn parse_amount(amount: &str) -> Result<f64, crate::Error> {
+    amount
+        .parse()
+        .map_err(|_| crate::Error::InvalidAmount(amount.to_string()))
+}
+
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn amount_to_f64(amount: &Amount) -> Result<f64, crate::Error> {
+    parse_amount(amount)
+}
+
+#[cfg(feature = "decimal")]
+pub(crate) fn amount_to_f64(amount: &Amount) -> Result<f64, crate::Error> {
+    use rust_decimal::prelude::ToPrimitive;
+    amount
+        .to_f64()
+        .ok_or_else(|| crate::Error::InvalidAmount(amount.to_string()))
+}
+
+/// Split a decimal string into its digits and the number of decimal places.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn scaled_amount(amount: &str) -> Result<(i128, u32), crate::Error> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(crate::Error::InvalidAmount(amount.to_string()));
+    }
+    let digits = format!("{}{}", integer, fraction)
+        .parse()
+        .map_err(|_| crate::Error::InvalidAmount(amount.to_string()))?;
+    Ok((digits, fraction.len() as u32))
+}
+
+/// Add `sign * other` to a decimal string without going through floating point.
+#[cfg(not(feature = "decimal"))]
+fn combine_amounts(amount: &str, other: &str, sign: i128) -> Result<String, crate::Error> {
+    let (a, a_scale) = scaled_amount(amount)?;
+    let (b, b_scale) = scaled_amount(other)?;
+    let scale = a_scale.max(b_scale);
+    let rescale = |digits: i128, from: u32| {
+        10i128
+            .checked_pow(scale - from)
+            .and_then(|factor| digits.checked_mul(factor))
+    };
+    let result = rescale(a, a_scale)
+        .zip(rescale(b, b_scale))
+        .and_then(|(a, b)| a.checked_add(sign * b))
+        .ok_or_else(|| crate::Error::InvalidAmount(format!("{} and {}", amount, other)))?;
+    Ok(unscaled_amount(result, scale))
+}
+
+/// Format digits with `scale` decimal places as a decimal string.
+#[cfg(not(feature = "decimal"))]
+fn unscaled_amount(digits: i128, scale: u32) -> String {
+    let sign = if digits < 0 { "-" } else { "" };
+    let digits = format!(
+        "{:0width$}",
+        digits.unsigned_abs(),
+        width = scale as usize + 1
+    );
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let (integer, fraction) = digits.split_at(digits.len() - scale as usize);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Add two amounts exactly.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn add_amount(amount: &Amount, other: &Amount) -> Result<Amount, crate::Error> {
+    combine_amounts(amount, other, 1)
+}
+
+/// Add two amounts exactly.
+#[cfg(feature = "decimal")]
+pub(crate) fn add_amount(amount: &Amount, other: &Amount) -> Result<Amount, crate::Error> {
+    amount
+        .checked_add(*other)
+        .ok_or_else(|| crate::Error::InvalidAmount(format!("{} + {}", amount, other)))
+}
+
+/// Subtract two decimal strings without going through floating point.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn subtract_amount(amount: &str, other: &str) -> Result<String, crate::Error> {
+    combine_amounts(amount, other, -1)
+}
+
+/// Subtract two amounts exactly.
+#[cfg(feature = "decimal")]
+pub(crate) fn subtract_amount(amount: &Amount, other: &Amount) -> Result<Amount, crate::Error> {
+    amount
+        .checked_sub(*other)
+        .ok_or_else(|| crate::Error::InvalidAmount(format!("{} - {}", amount, other)))
+}
+
+/// Multiply two decimal strings without going through floating point.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn multiply_amount(amount: &str, other: &str) -> Result<String, crate::Error> {
+    let (a, a_scale) = scaled_amount(amount)?;
+    let (b, b_scale) = scaled_amount(other)?;
+    let product = a
+        .checked_mul(b)
+        .ok_or_else(|| crate::Error::InvalidAmount(format!("{} * {}", amount, other)))?;
+    Ok(unscaled_amount(product, a_scale + b_scale))
+}
+
+/// Multiply two amounts exactly.
+#[cfg(feature = "decimal")]
+pub(crate) fn multiply_amount(amount: &Amount, other: &Amount) -> Result<Amount, crate::Error> {
+    amount
+        .checked_mul(*other)
+        .ok_or_else(|| crate::Error::InvalidAmount(format!("{} * {}", amount, other)))
+}
+
+/// Compare two amounts exactly.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn compare_amounts(amount: &Amount, other: &Amount) -> Result<Ordering, crate::Error> {
+    let (difference, _) = scaled_amount(&subtract_amount(amount, other)?)?;
+    Ok(difference.cmp(&0))
+}
+
+/// Compare two amounts exactly.
+#[cfg(feature = "decimal")]
+pub(crate) fn compare_amounts(amount: &Amount, other: &Amount) -> Result<Ordering, crate::Error> {
+    Ok(amount.cmp(other))
+}
+
+fn amount_from_str<E: de::Error>(amount: &str) -> Result<Amount, E> {
+    amount.parse().map_err(E::custom)
+}
+
+struct AmountVisitor;
+/// Luno reports some amounts as JSON numbers rather than strings, so accept both.
+pub fn deserialize_amount<'de, D>(d: D) -> Result<Amount, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    d.deserialize_any(AmountVisitor)
+}
+
+impl<'de> de::Visitor<'de> for AmountVisitor {
+    type Value = Amount;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "amount should be a string or a number")
+    }
+    fn visit_str<E>(self, amount: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        amount_from_str(amount)
+    }
+    fn visit_i64<E>(self, amount: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        amount_from_str(&amount.to_string())
+    }
+    fn visit_u64<E>(self, amount: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        amount_from_str(&amount.to_string())
+    }
+    fn visit_f64<E>(self, amount: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        amount_from_str(&amount.to_string())
+    }
+}
 
 struct OrderTypeVisitor;
 pub fn convert_is_buy_to_order_type<'de, D>(d: D) -> Result<OrderType, D::Error>
@@ -36,3 +267,44 @@ impl<'de> de::Visitor<'de> for OrderTypeVisitor {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "decimal"))]
+    use super::subtract_amount;
+    use super::{add_amount, multiply_amount, Amount};
+
+    #[test]
+    #[cfg(not(feature = "decimal"))]
+    fn subtracts_amounts_exactly() {
+        assert_eq!(subtract_amount("0.93", "0.1").unwrap(), "0.83");
+        assert_eq!(subtract_amount("1", "0.25").unwrap(), "0.75");
+        assert_eq!(subtract_amount("0.3", "0.1").unwrap(), "0.2");
+        assert_eq!(subtract_amount("0.1", "0.10").unwrap(), "0.00");
+        assert_eq!(subtract_amount("2", "3").unwrap(), "-1");
+        assert!(subtract_amount("1.2.3", "1").is_err());
+    }
+
+    #[test]
+    fn adds_amounts_exactly() {
+        let add = |a: &str, b: &str| {
+            let (a, b): (Amount, Amount) = (a.parse().unwrap(), b.parse().unwrap());
+            add_amount(&a, &b).unwrap().to_string()
+        };
+        assert_eq!(add("0.1", "0.2"), "0.3");
+        assert_eq!(add("0.5", "0.25"), "0.75");
+        assert_eq!(add("1", "0.001"), "1.001");
+        assert_eq!(add("-0.5", "0.25"), "-0.25");
+    }
+
+    #[test]
+    fn multiplies_amounts_exactly() {
+        let multiply = |a: &str, b: &str| {
+            let (a, b): (Amount, Amount) = (a.parse().unwrap(), b.parse().unwrap());
+            multiply_amount(&a, &b).unwrap().to_string()
+        };
+        assert_eq!(multiply("0.1", "0.2"), "0.02");
+        assert_eq!(multiply("98", "0.001"), "0.098");
+        assert_eq!(multiply("-1.5", "2"), "-3.0");
+    }
+}
@@ -1,12 +1,15 @@
+use crate::domain::{Amount, TimeInForce};
 use crate::Error;
 use chrono::serde::ts_milliseconds;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
 pub enum OrderType {
+    #[serde(alias = "BUY")]
     BID,
+    #[serde(alias = "SELL")]
     ASK,
 }
 impl fmt::Display for OrderType {
@@ -30,6 +33,45 @@ impl str::FromStr for OrderType {
     }
 }
 
+/// State used to filter orders when listing them.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderState {
+    Pending,
+    Complete,
+}
+
+impl fmt::Display for OrderState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderState::Pending => write!(f, "PENDING"),
+            OrderState::Complete => write!(f, "COMPLETE"),
+        }
+    }
+}
+
+/// Side of the trigger price at which a stop-limit order is activated.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StopDirection {
+    /// Trigger when the market price rises above the stop price
+    Above,
+    /// Trigger when the market price falls below the stop price
+    Below,
+    /// Infer the direction from the last trade price when the order is placed
+    RelativeLastTrade,
+}
+
+impl fmt::Display for StopDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopDirection::Above => write!(f, "ABOVE"),
+            StopDirection::Below => write!(f, "BELOW"),
+            StopDirection::RelativeLastTrade => write!(f, "RELATIVE_LAST_TRADE"),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Order {
     pub order_id: String,
@@ -42,34 +84,227 @@ pub struct Order {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub state: String,
-    pub limit_price: String,
-    pub limit_volume: String,
-    pub base: String,
-    pub counter: String,
-    pub fee_base: String,
-    pub fee_counter: String,
+    pub limit_price: Amount,
+    pub limit_volume: Amount,
+    pub base: Amount,
+    pub counter: Amount,
+    pub fee_base: Amount,
+    pub fee_counter: Amount,
     pub pair: String,
+    #[serde(default)]
+    pub stop_price: Option<Amount>,
+    #[serde(default)]
+    pub stop_direction: Option<StopDirection>,
+}
+
+/// Status of an order as reported by the v3 order endpoint.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    /// A stop-limit order that has not been triggered yet
+    Awaiting,
+    /// The order is on the order book and can still be filled
+    Pending,
+    /// The order is filled, cancelled or expired
+    Complete,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Kind of order as reported by the v3 order endpoint.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderKind {
+    Limit,
+    Market,
+    StopLimit,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Identifies an order either by the id assigned by Luno or by the client order id
+/// supplied when it was placed.
+#[derive(Debug)]
+pub enum OrderReference {
+    OrderId(String),
+    ClientOrderId(String),
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetOrderQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_order_id: Option<String>,
+}
+
+impl From<OrderReference> for GetOrderQuery {
+    fn from(reference: OrderReference) -> Self {
+        match reference {
+            OrderReference::OrderId(id) => GetOrderQuery {
+                id: Some(id),
+                ..Default::default()
+            },
+            OrderReference::ClientOrderId(client_order_id) => GetOrderQuery {
+                client_order_id: Some(client_order_id),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Order with fills, fees and status, as returned by `LunoClient::get_order`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OrderDetails {
+    pub order_id: String,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    pub pair: String,
+    pub side: OrderType,
+    #[serde(rename = "type")]
+    pub kind: OrderKind,
+    pub status: OrderStatus,
+    #[serde(default)]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(with = "ts_milliseconds")]
+    pub creation_timestamp: DateTime<Utc>,
+    #[serde(with = "ts_milliseconds")]
+    pub expiration_timestamp: DateTime<Utc>,
+    #[serde(with = "ts_milliseconds")]
+    pub completed_timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub limit_price: Option<Amount>,
+    #[serde(default)]
+    pub limit_volume: Option<Amount>,
+    #[serde(default)]
+    pub stop_price: Option<Amount>,
+    #[serde(default)]
+    pub stop_direction: Option<StopDirection>,
+    /// Amount of base currency filled
+    pub base: Amount,
+    /// Amount of counter currency filled
+    pub counter: Amount,
+    pub fee_base: Amount,
+    pub fee_counter: Amount,
 }
 
 #[derive(Deserialize)]
 pub struct ListOrdersResponse {
-    pub orders: Vec<Order>,
+    pub orders: Option<Vec<Order>>,
 }
 
 /// OrderBookEntry contains the limit price and available volume.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct OrderBookEntry {
     /// Limit price
-    pub price: String,
+    pub price: Amount,
     /// Volume available
-    pub volume: String,
+    pub volume: Amount,
 }
 
 /// Contains a list of all bids and asks for the currency pair specified in the Order Book
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OrderBook {
     pub asks: Vec<OrderBookEntry>,
     pub bids: Vec<OrderBookEntry>,
     #[serde(with = "ts_milliseconds")]
     pub timestamp: DateTime<Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        GetOrderQuery, ListOrdersResponse, Order, OrderDetails, OrderKind, OrderReference,
+        OrderStatus, OrderType, StopDirection,
+    };
+    use surf::http::{Method, Request, Url};
+
+    fn encode(query: GetOrderQuery) -> String {
+        let url = Url::parse("https://api.luno.com/api/exchange/3/order").unwrap();
+        let mut request = Request::new(Method::Get, url);
+        request.set_query(&query).unwrap();
+        request.url().query().unwrap_or_default().to_string()
+    }
+
+    #[test]
+    fn encodes_get_order_query() {
+        let by_id = GetOrderQuery::from(OrderReference::OrderId("BXMC2CJ7HNB88U4".into()));
+        assert_eq!(encode(by_id), "id=BXMC2CJ7HNB88U4");
+        let by_client_id =
+            GetOrderQuery::from(OrderReference::ClientOrderId("dca-2021-01-04".into()));
+        assert_eq!(encode(by_client_id), "client_order_id=dca-2021-01-04");
+    }
+
+    #[test]
+    fn parses_unknown_status_and_kind() {
+        let status: OrderStatus = serde_json::from_str(r#""SUSPENDED""#).unwrap();
+        assert_eq!(status, OrderStatus::Unknown);
+        let kind: OrderKind = serde_json::from_str(r#""TRAILING_STOP""#).unwrap();
+        assert_eq!(kind, OrderKind::Unknown);
+    }
+
+    #[test]
+    fn parses_empty_order_list() {
+        let response: ListOrdersResponse = serde_json::from_str(r#"{"orders": null}"#).unwrap();
+        assert!(response.orders.is_none());
+    }
+
+    #[test]
+    fn parses_untriggered_stop_order() {
+        let data = r#"
+        {
+            "order_id": "BXMC2CJ7HNB88U4",
+            "creation_timestamp": 1367849297609,
+            "expiration_timestamp": 0,
+            "completed_timestamp": 0,
+            "type": "ASK",
+            "state": "AWAITING",
+            "limit_price": "11000000.00",
+            "limit_volume": "0.10",
+            "base": "0.00",
+            "counter": "0.00",
+            "fee_base": "0.00",
+            "fee_counter": "0.00",
+            "pair": "XBTNGN",
+            "stop_price": "11500000.00",
+            "stop_direction": "BELOW"
+        }
+        "#;
+        let order: Order = serde_json::from_str(data).unwrap();
+        assert_eq!(
+            order.stop_price.map(|price| price.to_string()).as_deref(),
+            Some("11500000.00")
+        );
+        assert_eq!(order.stop_direction, Some(StopDirection::Below));
+    }
+
+    #[test]
+    fn parses_order_details() {
+        let data = r#"
+        {
+            "base": "0.00215000",
+            "client_order_id": "dca-2021-01-04",
+            "completed_timestamp": 1609748321523,
+            "counter": "50000.00",
+            "creation_timestamp": 1609748321481,
+            "expiration_timestamp": 0,
+            "fee_base": "0.00000215",
+            "fee_counter": "0.00",
+            "limit_price": "0",
+            "limit_volume": "0",
+            "order_id": "BXMC2CJ7HNB88U4",
+            "pair": "XBTNGN",
+            "side": "BUY",
+            "status": "COMPLETE",
+            "time_in_force": "IOC",
+            "type": "MARKET"
+        }
+        "#;
+        let order: OrderDetails = serde_json::from_str(data).unwrap();
+        assert_eq!(order.client_order_id.as_deref(), Some("dca-2021-01-04"));
+        assert!(matches!(order.side, OrderType::BID));
+        assert_eq!(order.kind, OrderKind::Market);
+        assert_eq!(order.status, OrderStatus::Complete);
+        assert_eq!(order.fee_base.to_string(), "0.00000215");
+    }
+}
@@ -0,0 +1,399 @@
+use crate::domain::{CurrencyPair, OrderState, OrderType, StopDirection};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Time in force of a limit order.
+#[derive(Debug, Deserialize, Serialize)]
+pub enum TimeInForce {
+    /// Good 'Til Cancelled. The order remains open until it is filled or cancelled.
+    GTC,
+    /// Immediate Or Cancel. The part of the order that cannot be filled immediately is cancelled.
+    IOC,
+    /// Fill Or Kill. The order is cancelled unless it can be filled immediately in full.
+    FOK,
+}
+
+impl fmt::Display for TimeInForce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeInForce::GTC => write!(f, "GTC"),
+            TimeInForce::IOC => write!(f, "IOC"),
+            TimeInForce::FOK => write!(f, "FOK"),
+        }
+    }
+}
+
+/// Parameters of a limit order to be placed with `LunoClient::post_limit_order`.
+///
+/// Use `PostOrderRequestBuilder` to create one.
+#[derive(Debug, Serialize)]
+pub struct PostOrderRequest {
+    pair: CurrencyPair,
+    #[serde(rename = "type")]
+    order_type: OrderType,
+    price: String,
+    volume: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_direction: Option<StopDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counter_account_id: Option<String>,
+}
+
+pub struct PostOrderRequestBuilder {
+    request: PostOrderRequest,
+}
+
+impl PostOrderRequestBuilder {
+    /// Create a new PostOrderRequestBuilder for a limit order of `volume` at `price`
+    pub fn new<A: fmt::Display>(
+        currency_pair: CurrencyPair,
+        order_type: OrderType,
+        price: A,
+        volume: A,
+    ) -> Self {
+        PostOrderRequestBuilder {
+            request: PostOrderRequest {
+                pair: currency_pair,
+                order_type,
+                price: price.to_string(),
+                volume: volume.to_string(),
+                stop_price: None,
+                stop_direction: None,
+                post_only: None,
+                time_in_force: None,
+                client_order_id: None,
+                base_account_id: None,
+                counter_account_id: None,
+            },
+        }
+    }
+
+    /// Turn the order into a stop-limit order that is only placed once the market
+    /// crosses `stop_price` in the given direction
+    pub fn with_stop<A: fmt::Display>(
+        mut self,
+        stop_price: A,
+        stop_direction: StopDirection,
+    ) -> Self {
+        self.request.stop_price = Some(stop_price.to_string());
+        self.request.stop_direction = Some(stop_direction);
+        self
+    }
+
+    /// Only place the order if it would not trade immediately (maker only)
+    pub fn with_post_only(mut self) -> Self {
+        self.request.post_only = Some(true);
+        self
+    }
+
+    /// Add time in force. Luno defaults to GTC when this is not set
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.request.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Add a client order id which must be unique across all orders on the Luno profile
+    pub fn with_client_order_id<T: AsRef<str>>(mut self, client_order_id: T) -> Self {
+        self.request.client_order_id = Some(client_order_id.as_ref().into());
+        self
+    }
+
+    /// Add the account to use for the base currency of the currency pair
+    pub fn with_base_account_id<T: AsRef<str>>(mut self, account_id: T) -> Self {
+        self.request.base_account_id = Some(account_id.as_ref().into());
+        self
+    }
+
+    /// Add the account to use for the counter currency of the currency pair
+    pub fn with_counter_account_id<T: AsRef<str>>(mut self, account_id: T) -> Self {
+        self.request.counter_account_id = Some(account_id.as_ref().into());
+        self
+    }
+
+    /// Build PostOrderRequestBuilder into a PostOrderRequest
+    pub fn build(self) -> PostOrderRequest {
+        self.request
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+enum MarketOrderSide {
+    #[serde(rename = "BUY")]
+    Buy { counter_volume: String },
+    #[serde(rename = "SELL")]
+    Sell { base_volume: String },
+}
+
+/// Parameters of a market order to be placed with `LunoClient::post_market_order`.
+///
+/// Use `PostMarketOrderRequestBuilder` to create one. A buy is always denominated in the
+/// counter currency and a sell in the base currency, as required by Luno.
+#[derive(Debug, Serialize)]
+pub struct PostMarketOrderRequest {
+    pair: CurrencyPair,
+    #[serde(flatten)]
+    side: MarketOrderSide,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counter_account_id: Option<String>,
+}
+
+impl PostMarketOrderRequest {
+    /// The order type matching the side of this market order
+    pub fn order_type(&self) -> OrderType {
+        match self.side {
+            MarketOrderSide::Buy { .. } => OrderType::BID,
+            MarketOrderSide::Sell { .. } => OrderType::ASK,
+        }
+    }
+}
+
+pub struct PostMarketOrderRequestBuilder {
+    request: PostMarketOrderRequest,
+}
+
+impl PostMarketOrderRequestBuilder {
+    /// Create a new PostMarketOrderRequestBuilder that spends `counter_volume` of the counter currency
+    pub fn buy<A: fmt::Display>(currency_pair: CurrencyPair, counter_volume: A) -> Self {
+        Self::new(
+            currency_pair,
+            MarketOrderSide::Buy {
+                counter_volume: counter_volume.to_string(),
+            },
+        )
+    }
+
+    /// Create a new PostMarketOrderRequestBuilder that sells `base_volume` of the base currency
+    pub fn sell<A: fmt::Display>(currency_pair: CurrencyPair, base_volume: A) -> Self {
+        Self::new(
+            currency_pair,
+            MarketOrderSide::Sell {
+                base_volume: base_volume.to_string(),
+            },
+        )
+    }
+
+    fn new(currency_pair: CurrencyPair, side: MarketOrderSide) -> Self {
+        PostMarketOrderRequestBuilder {
+            request: PostMarketOrderRequest {
+                pair: currency_pair,
+                side,
+                client_order_id: None,
+                base_account_id: None,
+                counter_account_id: None,
+            },
+        }
+    }
+
+    /// Add a client order id which must be unique across all orders on the Luno profile
+    pub fn with_client_order_id<T: AsRef<str>>(mut self, client_order_id: T) -> Self {
+        self.request.client_order_id = Some(client_order_id.as_ref().into());
+        self
+    }
+
+    /// Add the account to use for the base currency of the currency pair
+    pub fn with_base_account_id<T: AsRef<str>>(mut self, account_id: T) -> Self {
+        self.request.base_account_id = Some(account_id.as_ref().into());
+        self
+    }
+
+    /// Add the account to use for the counter currency of the currency pair
+    pub fn with_counter_account_id<T: AsRef<str>>(mut self, account_id: T) -> Self {
+        self.request.counter_account_id = Some(account_id.as_ref().into());
+        self
+    }
+
+    /// Build PostMarketOrderRequestBuilder into a PostMarketOrderRequest
+    pub fn build(self) -> PostMarketOrderRequest {
+        self.request
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PostOrderResponse {
+    pub order_id: String,
+}
+
+/// Filters for listing orders with `LunoClient::list_orders_with_query` and
+/// `LunoClient::list_orders_stream`.
+///
+/// # Examples
+///
+/// ```
+/// use luno_rs::{CurrencyPair, ListOrdersQuery, OrderState};
+/// let query = ListOrdersQuery::new()
+///     .with_state(OrderState::Complete)
+///     .with_currency_pair(CurrencyPair::XBTNGN)
+///     .with_limit(100);
+/// ```
+#[derive(Debug, Default, Serialize)]
+pub struct ListOrdersQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<OrderState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<CurrencyPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_before: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+}
+
+impl ListOrdersQuery {
+    /// Create a new ListOrdersQuery that matches all orders
+    pub fn new() -> Self {
+        ListOrdersQuery::default()
+    }
+
+    /// Only list orders in this state
+    pub fn with_state(mut self, state: OrderState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Only list orders for this currency pair
+    pub fn with_currency_pair(mut self, currency_pair: CurrencyPair) -> Self {
+        self.pair = Some(currency_pair);
+        self
+    }
+
+    /// Only list orders created before this time
+    pub fn with_created_before(mut self, created_before: DateTime<Utc>) -> Self {
+        self.created_before = Some(created_before.timestamp_millis());
+        self
+    }
+
+    /// Limit the number of orders returned per request
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub(crate) fn limit(&self) -> Option<u32> {
+        self.limit
+    }
+
+    pub(crate) fn created_before_millis(&self) -> Option<i64> {
+        self.created_before
+    }
+
+    pub(crate) fn set_created_before_millis(&mut self, created_before: i64) {
+        self.created_before = Some(created_before);
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct StopOrderRequest {
+    order_id: String,
+}
+
+impl StopOrderRequest {
+    pub fn new(order_id: &str) -> Self {
+        StopOrderRequest {
+            order_id: order_id.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ListOrdersQuery, PostMarketOrderRequestBuilder, PostOrderRequestBuilder, TimeInForce,
+    };
+    use crate::domain::{CurrencyPair, OrderState, OrderType, StopDirection};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn serializes_only_provided_fields() {
+        let request =
+            PostOrderRequestBuilder::new(CurrencyPair::XBTNGN, OrderType::BID, "12000000", "0.01")
+                .with_time_in_force(TimeInForce::IOC)
+                .with_client_order_id("desk-1")
+                .build();
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "pair": "XBTNGN",
+                "type": "BID",
+                "price": "12000000",
+                "volume": "0.01",
+                "time_in_force": "IOC",
+                "client_order_id": "desk-1"
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_market_buy_with_counter_volume() {
+        let request = PostMarketOrderRequestBuilder::buy(CurrencyPair::XBTNGN, "50000").build();
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "pair": "XBTNGN",
+                "type": "BUY",
+                "counter_volume": "50000"
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_market_sell_with_base_volume() {
+        let request = PostMarketOrderRequestBuilder::sell(CurrencyPair::XBTNGN, "0.01")
+            .with_base_account_id("1224342323")
+            .build();
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "pair": "XBTNGN",
+                "type": "SELL",
+                "base_volume": "0.01",
+                "base_account_id": "1224342323"
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_stop_limit_order() {
+        let request =
+            PostOrderRequestBuilder::new(CurrencyPair::XBTNGN, OrderType::ASK, "11000000", "0.1")
+                .with_stop("11500000", StopDirection::Below)
+                .build();
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["stop_price"], "11500000");
+        assert_eq!(value["stop_direction"], "BELOW");
+    }
+
+    #[test]
+    fn serializes_list_orders_query() {
+        let query = ListOrdersQuery::new()
+            .with_state(OrderState::Complete)
+            .with_created_before(Utc.timestamp_millis_opt(1609241817077).unwrap())
+            .with_limit(50);
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "state": "COMPLETE",
+                "created_before": 1609241817077i64,
+                "limit": 50
+            })
+        );
+    }
+}
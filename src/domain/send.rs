@@ -0,0 +1,150 @@
+use crate::domain::{Amount, Asset};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Parameters of a send to be requested with `LunoClient::send`.
+///
+/// Use `SendRequestBuilder` to create one.
+#[derive(Debug, Serialize)]
+pub struct SendRequest {
+    amount: String,
+    currency: Asset,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_destination_tag: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    destination_tag: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_id: Option<String>,
+}
+
+pub struct SendRequestBuilder {
+    request: SendRequest,
+}
+
+impl SendRequestBuilder {
+    /// Create a new SendRequestBuilder that sends `amount` of `currency` to a cryptocurrency
+    /// address or to the email address of a Luno user
+    pub fn new<A: fmt::Display, T: AsRef<str>>(amount: A, currency: Asset, address: T) -> Self {
+        SendRequestBuilder {
+            request: SendRequest {
+                amount: amount.to_string(),
+                currency,
+                address: address.as_ref().into(),
+                description: None,
+                message: None,
+                has_destination_tag: None,
+                destination_tag: None,
+                memo: None,
+                external_id: None,
+                account_id: None,
+            },
+        }
+    }
+
+    /// Add a description that is shown in the transaction list of the sender
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.request.description = Some(description.as_ref().into());
+        self
+    }
+
+    /// Add a message to the recipient, only used when sending to an email address
+    pub fn with_message<T: AsRef<str>>(mut self, message: T) -> Self {
+        self.request.message = Some(message.as_ref().into());
+        self
+    }
+
+    /// Add the destination tag required by some XRP addresses
+    pub fn with_destination_tag(mut self, destination_tag: u64) -> Self {
+        self.request.has_destination_tag = Some(true);
+        self.request.destination_tag = Some(destination_tag);
+        self
+    }
+
+    /// Add the memo required by some addresses
+    pub fn with_memo<T: AsRef<str>>(mut self, memo: T) -> Self {
+        self.request.memo = Some(memo.as_ref().into());
+        self
+    }
+
+    /// Add an external id which must be unique across all sends on the Luno profile.
+    /// Requesting the same external id again does not send the funds twice
+    pub fn with_external_id<T: AsRef<str>>(mut self, external_id: T) -> Self {
+        self.request.external_id = Some(external_id.as_ref().into());
+        self
+    }
+
+    /// Add the account to send from. Luno uses the default account of the currency when
+    /// this is not set
+    pub fn with_account_id<T: AsRef<str>>(mut self, account_id: T) -> Self {
+        self.request.account_id = Some(account_id.as_ref().into());
+        self
+    }
+
+    /// Build SendRequestBuilder into a SendRequest
+    pub fn build(self) -> SendRequest {
+        self.request
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SendResponse {
+    pub withdrawal_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SendFeeQuery<'a> {
+    amount: String,
+    currency: Asset,
+    address: &'a str,
+}
+
+impl<'a> SendFeeQuery<'a> {
+    pub fn new<A: fmt::Display>(amount: A, currency: Asset, address: &'a str) -> Self {
+        SendFeeQuery {
+            amount: amount.to_string(),
+            currency,
+            address,
+        }
+    }
+}
+
+/// Fee that Luno charges to send an amount to an address.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SendFee {
+    pub currency: String,
+    pub fee: Amount,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SendRequestBuilder;
+    use crate::domain::Asset;
+
+    #[test]
+    fn serializes_destination_tag() {
+        let request = SendRequestBuilder::new("25", Asset::XRP, "rLuno1ExampLeXRPaDdRe55")
+            .with_destination_tag(1234567)
+            .with_external_id("payout-42")
+            .build();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "amount": "25",
+                "currency": "XRP",
+                "address": "rLuno1ExampLeXRPaDdRe55",
+                "has_destination_tag": true,
+                "destination_tag": 1234567,
+                "external_id": "payout-42"
+            })
+        );
+    }
+}
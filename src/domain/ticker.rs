@@ -1,13 +1,14 @@
+use super::Amount;
 use chrono::serde::ts_milliseconds;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Ticker {
-    pub ask: String,
-    pub bid: String,
-    pub last_trade: String,
+    pub ask: Amount,
+    pub bid: Amount,
+    pub last_trade: Amount,
     pub pair: String,
-    pub rolling_24_hour_volume: String,
+    pub rolling_24_hour_volume: Amount,
     pub status: String,
     #[serde(with = "ts_milliseconds")]
     pub timestamp: DateTime<Utc>,
@@ -42,4 +43,24 @@ mod tests {
             "2020-12-29 11:36:57.077 UTC"
         );
     }
+
+    #[test]
+    #[cfg(feature = "decimal")]
+    fn parses_prices_into_decimals() {
+        let data = r#"
+        {
+            "pair": "XBTNGN",
+            "timestamp": 1609241817077,
+            "bid": "12579999.00000000",
+            "ask": "12580000.00000000",
+            "last_trade": "12560699.00000000",
+            "rolling_24_hour_volume": "205.51107600",
+            "status": "ACTIVE"
+        }
+        "#;
+        let ticker: Ticker = serde_json::from_str(data).unwrap();
+        assert_eq!(ticker.ask - ticker.bid, "1".parse().unwrap());
+        let value = serde_json::to_value(&ticker).unwrap();
+        assert_eq!(value["rolling_24_hour_volume"], "205.51107600");
+    }
 }
@@ -1,21 +1,181 @@
 use super::convert_is_buy_to_order_type;
-use crate::domain::OrderType;
+use crate::domain::{Amount, CurrencyPair, OrderType};
 use chrono::serde::ts_milliseconds;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Trade {
-    pub price: String,
+    pub price: Amount,
     pub sequence: i64,
     #[serde(with = "ts_milliseconds")]
     pub timestamp: DateTime<Utc>,
     #[serde(rename = "is_buy", deserialize_with = "convert_is_buy_to_order_type")]
     pub order_type: OrderType,
-    pub volume: String,
+    pub volume: Amount,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct ListTradesResponse {
     pub trades: Vec<Trade>,
 }
+
+/// Trade executed by one of the orders on the Luno profile.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserTrade {
+    pub pair: String,
+    pub sequence: i64,
+    pub order_id: String,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub is_buy: bool,
+    #[serde(with = "ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub price: Amount,
+    pub volume: Amount,
+    /// Amount of base currency exchanged
+    pub base: Amount,
+    /// Amount of counter currency exchanged
+    pub counter: Amount,
+    pub fee_base: Amount,
+    pub fee_counter: Amount,
+}
+
+#[derive(Deserialize)]
+pub struct ListUserTradesResponse {
+    pub trades: Option<Vec<UserTrade>>,
+}
+
+/// Filters for listing trades with `LunoClient::list_user_trades` and
+/// `LunoClient::list_user_trades_stream`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, Utc};
+/// use luno_rs::{CurrencyPair, ListUserTradesQuery};
+/// let query = ListUserTradesQuery::new(CurrencyPair::XBTNGN)
+///     .with_since(Utc::now() - Duration::days(7))
+///     .with_limit(100);
+/// ```
+#[derive(Debug, Serialize)]
+pub struct ListUserTradesQuery {
+    pair: CurrencyPair,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after_seq: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before_seq: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_desc: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+}
+
+impl ListUserTradesQuery {
+    /// Create a new ListUserTradesQuery for trades in a currency pair
+    pub fn new(currency_pair: CurrencyPair) -> Self {
+        ListUserTradesQuery {
+            pair: currency_pair,
+            since: None,
+            before: None,
+            after_seq: None,
+            before_seq: None,
+            sort_desc: None,
+            limit: None,
+        }
+    }
+
+    /// Only list trades at or after this time
+    pub fn with_since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since.timestamp_millis());
+        self
+    }
+
+    /// Only list trades before this time
+    pub fn with_before(mut self, before: DateTime<Utc>) -> Self {
+        self.before = Some(before.timestamp_millis());
+        self
+    }
+
+    /// Only list trades with a sequence number greater than or equal to this one
+    pub fn with_after_seq(mut self, after_seq: i64) -> Self {
+        self.after_seq = Some(after_seq);
+        self
+    }
+
+    /// Only list trades with a sequence number less than this one
+    pub fn with_before_seq(mut self, before_seq: i64) -> Self {
+        self.before_seq = Some(before_seq);
+        self
+    }
+
+    /// List the newest trades first
+    pub fn with_sort_desc(mut self) -> Self {
+        self.sort_desc = Some(true);
+        self
+    }
+
+    /// Limit the number of trades returned per request
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub(crate) fn limit(&self) -> Option<u32> {
+        self.limit
+    }
+
+    pub(crate) fn is_sort_desc(&self) -> bool {
+        self.sort_desc.unwrap_or(false)
+    }
+
+    pub(crate) fn set_after_seq(&mut self, after_seq: i64) {
+        self.after_seq = Some(after_seq);
+    }
+
+    pub(crate) fn set_before_seq(&mut self, before_seq: i64) {
+        self.before_seq = Some(before_seq);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ListUserTradesResponse, UserTrade};
+
+    #[test]
+    fn parses_user_trade() {
+        let data = r#"
+        {
+            "base": "0.147741",
+            "client_order_id": "desk-1",
+            "counter": "1549.950831",
+            "fee_base": "0.00",
+            "fee_counter": "1.55",
+            "is_buy": false,
+            "order_id": "BXMC2CJ7HNB88U4",
+            "pair": "XBTZAR",
+            "price": "10491.00",
+            "sequence": 429,
+            "timestamp": 1467138492000,
+            "type": "ASK",
+            "volume": "0.147741"
+        }
+        "#;
+        let trade: UserTrade = serde_json::from_str(data).unwrap();
+        assert!(!trade.is_buy);
+        assert_eq!(trade.fee_counter.to_string(), "1.55");
+        assert_eq!(trade.client_order_id.as_deref(), Some("desk-1"));
+    }
+
+    #[test]
+    fn parses_empty_trade_list() {
+        let response: ListUserTradesResponse = serde_json::from_str(r#"{"trades": null}"#).unwrap();
+        assert!(response.trades.is_none());
+    }
+}
@@ -0,0 +1,133 @@
+use super::{deserialize_amount, Amount};
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kind of entry in the transaction ledger of an account.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransactionKind {
+    Exchange,
+    Fee,
+    Interest,
+    Transfer,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Entry in the transaction ledger of an account.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Transaction {
+    /// Position of the entry in the ledger, starting at 1
+    pub row_index: i64,
+    #[serde(with = "ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    /// Balance after this entry
+    #[serde(deserialize_with = "deserialize_amount")]
+    pub balance: Amount,
+    /// Available balance after this entry
+    #[serde(deserialize_with = "deserialize_amount")]
+    pub available: Amount,
+    #[serde(deserialize_with = "deserialize_amount")]
+    pub balance_delta: Amount,
+    #[serde(deserialize_with = "deserialize_amount")]
+    pub available_delta: Amount,
+    pub currency: String,
+    pub description: String,
+    pub kind: TransactionKind,
+}
+
+#[derive(Deserialize)]
+pub struct ListTransactionsResponse {
+    pub transactions: Option<Vec<Transaction>>,
+}
+
+#[derive(Deserialize)]
+pub struct ListPendingTransactionsResponse {
+    pub pending: Option<Vec<Transaction>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListTransactionsQuery {
+    min_row: i64,
+    max_row: i64,
+}
+
+impl ListTransactionsQuery {
+    pub fn new(min_row: i64, max_row: i64) -> Self {
+        ListTransactionsQuery { min_row, max_row }
+    }
+
+    pub(crate) fn min_row(&self) -> i64 {
+        self.min_row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ListPendingTransactionsResponse, ListTransactionsResponse, TransactionKind};
+
+    #[test]
+    fn parses_json_correctly() {
+        let data = r#"
+        {
+            "id": "319232323",
+            "transactions": [
+                {
+                    "row_index": 2,
+                    "timestamp": 1387527013000,
+                    "balance": 0.0199,
+                    "available": 0.0199,
+                    "balance_delta": 0.0199,
+                    "available_delta": 0.0199,
+                    "currency": "XBT",
+                    "description": "Bought BTC 0.0199 for NGN 100000.00",
+                    "kind": "EXCHANGE"
+                },
+                {
+                    "row_index": 1,
+                    "timestamp": 1387527012000,
+                    "balance": "0.00",
+                    "available": "0.00",
+                    "balance_delta": "0.00",
+                    "available_delta": "0.00",
+                    "currency": "XBT",
+                    "description": "Account opened",
+                    "kind": "SOMETHING_NEW"
+                }
+            ]
+        }
+        "#;
+        let response: ListTransactionsResponse = serde_json::from_str(data).unwrap();
+        let transactions = response.transactions.unwrap();
+        assert_eq!(transactions[0].balance.to_string(), "0.0199");
+        assert_eq!(transactions[0].kind, TransactionKind::Exchange);
+        assert_eq!(transactions[1].kind, TransactionKind::Unknown);
+    }
+
+    #[test]
+    fn parses_pending_transactions() {
+        let data = r#"
+        {
+            "id": "319232323",
+            "pending": [
+                {
+                    "row_index": 0,
+                    "timestamp": 1387527013000,
+                    "balance": "0.10",
+                    "available": "0.00",
+                    "balance_delta": "0.10",
+                    "available_delta": "0.00",
+                    "currency": "XBT",
+                    "description": "Received Bitcoin - unconfirmed",
+                    "kind": "TRANSFER"
+                }
+            ]
+        }
+        "#;
+        let response: ListPendingTransactionsResponse = serde_json::from_str(data).unwrap();
+        let pending = response.pending.unwrap();
+        assert_eq!(pending[0].balance_delta.to_string(), "0.10");
+        assert_eq!(pending[0].kind, TransactionKind::Transfer);
+    }
+}
@@ -0,0 +1,113 @@
+use super::Amount;
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Bank account that fiat withdrawals can be paid out to.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Beneficiary {
+    pub id: String,
+    pub bank_name: String,
+    pub bank_country: String,
+    pub bank_account_branch: String,
+    pub bank_account_number: String,
+    pub bank_account_type: String,
+    pub bank_recipient: String,
+    #[serde(with = "ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub supports_fast_withdrawals: bool,
+}
+
+#[derive(Deserialize)]
+pub struct ListBeneficiariesResponse {
+    pub beneficiaries: Option<Vec<Beneficiary>>,
+}
+
+/// Completed on-chain or bank transfer into or out of an account.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Transfer {
+    pub id: String,
+    /// Amount credited or debited, including the fee
+    pub amount: Amount,
+    pub fee: Amount,
+    #[serde(with = "ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
+    pub inbound: bool,
+    #[serde(default)]
+    pub transaction_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ListTransfersResponse {
+    pub transfers: Option<Vec<Transfer>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListTransfersQuery<'a> {
+    account_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+}
+
+impl<'a> ListTransfersQuery<'a> {
+    pub fn new(account_id: &'a str, before: Option<DateTime<Utc>>, limit: Option<u32>) -> Self {
+        ListTransfersQuery {
+            account_id,
+            before: before.map(|before| before.timestamp_millis()),
+            limit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ListBeneficiariesResponse, ListTransfersResponse};
+
+    #[test]
+    fn parses_beneficiaries() {
+        let data = r#"
+        {
+            "beneficiaries": [
+                {
+                    "id": "1234",
+                    "bank_name": "Example Bank",
+                    "bank_country": "NG",
+                    "bank_account_branch": "",
+                    "bank_account_number": "0123456789",
+                    "bank_account_type": "Current/Cheque",
+                    "bank_recipient": "Example Ltd",
+                    "created_at": 1609748321481,
+                    "supports_fast_withdrawals": false
+                }
+            ]
+        }
+        "#;
+        let response: ListBeneficiariesResponse = serde_json::from_str(data).unwrap();
+        assert_eq!(response.beneficiaries.unwrap()[0].id, "1234");
+    }
+
+    #[test]
+    fn parses_transfers() {
+        let data = r#"
+        {
+            "transfers": [
+                {
+                    "id": "98765",
+                    "amount": "0.5",
+                    "fee": "0.0001",
+                    "created_at": 1609748321481,
+                    "inbound": true,
+                    "transaction_id": "4d1c6e0b..."
+                }
+            ]
+        }
+        "#;
+        let response: ListTransfersResponse = serde_json::from_str(data).unwrap();
+        let transfers = response.transfers.unwrap();
+        assert!(transfers[0].inbound);
+        assert_eq!(transfers[0].fee.to_string(), "0.0001");
+    }
+}
@@ -0,0 +1,174 @@
+use super::Amount;
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Status of a withdrawal.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WithdrawalStatus {
+    Pending,
+    Processing,
+    Completed,
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Withdrawal of funds out of Luno profile.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Withdrawal {
+    pub id: String,
+    pub status: WithdrawalStatus,
+    #[serde(with = "ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
+    /// Withdrawal method, for example `ZAR_EFT` or `NGN_DEFAULT`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub currency: String,
+    pub amount: Amount,
+    pub fee: Amount,
+    #[serde(default)]
+    pub external_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ListWithdrawalsResponse {
+    pub withdrawals: Option<Vec<Withdrawal>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ListWithdrawalsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+}
+
+impl ListWithdrawalsQuery {
+    pub fn new(before_id: Option<i64>, limit: Option<u32>) -> Self {
+        ListWithdrawalsQuery { before_id, limit }
+    }
+
+    pub(crate) fn limit(&self) -> Option<u32> {
+        self.limit
+    }
+
+    pub(crate) fn set_before_id(&mut self, before_id: i64) {
+        self.before_id = Some(before_id);
+    }
+}
+
+/// Parameters of a withdrawal to be requested with `LunoClient::create_withdrawal`.
+///
+/// Use `CreateWithdrawalRequestBuilder` to create one.
+#[derive(Debug, Serialize)]
+pub struct CreateWithdrawalRequest {
+    #[serde(rename = "type")]
+    kind: String,
+    amount: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    beneficiary_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fast: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+}
+
+pub struct CreateWithdrawalRequestBuilder {
+    request: CreateWithdrawalRequest,
+}
+
+impl CreateWithdrawalRequestBuilder {
+    /// Create a new CreateWithdrawalRequestBuilder for `amount` using a withdrawal method
+    /// such as `ZAR_EFT` or `NGN_DEFAULT`
+    pub fn new<T: AsRef<str>, A: fmt::Display>(kind: T, amount: A) -> Self {
+        CreateWithdrawalRequestBuilder {
+            request: CreateWithdrawalRequest {
+                kind: kind.as_ref().into(),
+                amount: amount.to_string(),
+                beneficiary_id: None,
+                fast: None,
+                reference: None,
+                external_id: None,
+            },
+        }
+    }
+
+    /// Add the beneficiary to pay out to. Luno uses the default beneficiary when this is not set
+    pub fn with_beneficiary_id<T: AsRef<str>>(mut self, beneficiary_id: T) -> Self {
+        self.request.beneficiary_id = Some(beneficiary_id.as_ref().into());
+        self
+    }
+
+    /// Request a fast withdrawal, which incurs a higher fee
+    pub fn with_fast(mut self) -> Self {
+        self.request.fast = Some(true);
+        self
+    }
+
+    /// Add a reference to show on the beneficiary's statement
+    pub fn with_reference<T: AsRef<str>>(mut self, reference: T) -> Self {
+        self.request.reference = Some(reference.as_ref().into());
+        self
+    }
+
+    /// Add an external id which must be unique across all withdrawals on the Luno profile
+    pub fn with_external_id<T: AsRef<str>>(mut self, external_id: T) -> Self {
+        self.request.external_id = Some(external_id.as_ref().into());
+        self
+    }
+
+    /// Build CreateWithdrawalRequestBuilder into a CreateWithdrawalRequest
+    pub fn build(self) -> CreateWithdrawalRequest {
+        self.request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CreateWithdrawalRequestBuilder, ListWithdrawalsResponse, WithdrawalStatus};
+
+    #[test]
+    fn parses_json_correctly() {
+        let data = r#"
+        {
+            "withdrawals": [
+                {
+                    "id": "1212",
+                    "status": "PENDING",
+                    "created_at": 1609748321481,
+                    "type": "NGN_DEFAULT",
+                    "currency": "NGN",
+                    "amount": "500000.00",
+                    "fee": "100.00"
+                }
+            ]
+        }
+        "#;
+        let response: ListWithdrawalsResponse = serde_json::from_str(data).unwrap();
+        let withdrawals = response.withdrawals.unwrap();
+        assert_eq!(withdrawals[0].status, WithdrawalStatus::Pending);
+        assert_eq!(withdrawals[0].kind, "NGN_DEFAULT");
+    }
+
+    #[test]
+    fn serializes_only_provided_fields() {
+        let request = CreateWithdrawalRequestBuilder::new("NGN_DEFAULT", "500000.00")
+            .with_beneficiary_id("1234")
+            .with_fast()
+            .build();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "type": "NGN_DEFAULT",
+                "amount": "500000.00",
+                "beneficiary_id": "1234",
+                "fast": true
+            })
+        );
+    }
+}
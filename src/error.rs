@@ -20,7 +20,15 @@ pub enum Error {
     TimeoutError(String),
     ApiError(LunoError),
     InvalidCurrencyPair(String),
+    InvalidAsset(String),
     InvalidOrderType(String),
+    InvalidAmount(String),
+    InvalidOrder(String),
+    SequenceGap(i64, i64),
+    /// A trade was reported against an order missing from the order book
+    UnknownOrder(String),
+    DeserializeError(String),
+    WebSocketError(String),
 }
 
 impl fmt::Display for Error {
@@ -33,9 +41,28 @@ impl fmt::Display for Error {
             Error::InvalidCurrencyPair(str) => {
                 write!(f, "Cannot convert {} to any currency pair", str)
             }
+            Error::InvalidAsset(str) => {
+                write!(f, "Cannot convert {} to any asset", str)
+            }
             Error::InvalidOrderType(str) => {
                 write!(f, "Cannot convert {} to any order type", str)
             }
+            Error::InvalidAmount(str) => {
+                write!(f, "Cannot convert {} to a number", str)
+            }
+            Error::InvalidOrder(message) => write!(f, "Invalid order: {}", message),
+            Error::SequenceGap(after, before) => {
+                write!(
+                    f,
+                    "Missing sequence numbers between {} and {}",
+                    after, before
+                )
+            }
+            Error::UnknownOrder(order_id) => {
+                write!(f, "Trade against unknown order {}", order_id)
+            }
+            Error::DeserializeError(message) => write!(f, "Deserialize Error: {}", message),
+            Error::WebSocketError(message) => write!(f, "WebSocket Error: {}", message),
             _ => write!(f, "Unable to process request at this time"),
         }
     }
@@ -53,6 +80,18 @@ impl From<surf::Error> for Error {
     }
 }
 
+impl From<async_tungstenite::tungstenite::Error> for Error {
+    fn from(err: async_tungstenite::tungstenite::Error) -> Self {
+        Error::WebSocketError(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::DeserializeError(err.to_string())
+    }
+}
+
 impl From<TimeoutError> for Error {
     fn from(err: TimeoutError) -> Self {
         Error::TimeoutError(err.to_string())
@@ -3,9 +3,12 @@ use crate::error::{Error, LunoError};
 use crate::middleware::Logger;
 use async_std::future::timeout;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::convert::TryInto;
 use std::time::Duration;
-use surf::http::mime;
-use surf::{StatusCode, Url};
+use surf::http::{mime, Method};
+use surf::{Body, Config, Request, StatusCode, Url};
+use url::form_urlencoded;
 pub struct Http {
     basic_auth: String,
     timeout: Duration,
@@ -13,23 +16,19 @@ pub struct Http {
 }
 
 impl Http {
-    pub fn new(key_id: String, key_secret: String) -> Self {
-        let credential = Credential::new(key_id, key_secret);
-        let timeout = Duration::from_millis(60000);
-        Http::new_with_features(credential, timeout, false)
-    }
-
     pub fn new_with_features(
         credential: Credential,
         timeout: Duration,
         enable_logger_middleware: bool,
     ) -> Self {
-        let mut client = surf::client();
+        let mut client: surf::Client = Config::new()
+            .set_base_url(Url::parse("https://api.luno.com").unwrap())
+            .try_into()
+            .unwrap();
         if enable_logger_middleware {
             client = client.with(Logger);
         }
 
-        client.set_base_url(Url::parse("https://api.luno.com").unwrap());
         Http {
             basic_auth: credential.get_basic_auth(),
             client,
@@ -47,7 +46,53 @@ impl Http {
             .header("Authorization", format!("Basic {}", self.basic_auth))
             .content_type(mime::JSON)
             .build();
+        self.send_request(request).await
+    }
+
+    pub async fn process_delete_request<T: DeserializeOwned, S: AsRef<str>>(
+        &self,
+        path: S,
+    ) -> Result<T, Error> {
+        let request = self
+            .client
+            .delete(path)
+            .header("Authorization", format!("Basic {}", self.basic_auth))
+            .content_type(mime::JSON)
+            .build();
+        self.send_request(request).await
+    }
+
+    pub async fn process_query_request<T: DeserializeOwned, S: AsRef<str>, Q: Serialize>(
+        &self,
+        path: S,
+        query: &Q,
+    ) -> Result<T, Error> {
+        let request = self
+            .client
+            .get(path)
+            .header("Authorization", format!("Basic {}", self.basic_auth))
+            .content_type(mime::JSON)
+            .query(query)?
+            .build();
+        self.send_request(request).await
+    }
+
+    pub async fn process_form_request<T: DeserializeOwned, S: AsRef<str>, F: Serialize>(
+        &self,
+        method: Method,
+        path: S,
+        form: &F,
+    ) -> Result<T, Error> {
+        let request = self
+            .client
+            .request(method, path)
+            .header("Authorization", format!("Basic {}", self.basic_auth))
+            .body(Body::from_form(form)?)
+            .build();
+        self.send_request(request).await
+    }
 
+    async fn send_request<T: DeserializeOwned>(&self, request: Request) -> Result<T, Error> {
         let mut response = timeout(self.timeout, self.client.send(request)).await??;
         if response.status() == StatusCode::Ok {
             Ok(response.take_body().into_json().await?)
@@ -57,3 +102,41 @@ impl Http {
         }
     }
 }
+
+/// Append `key=value` to `path` for every value. serde_qs, which surf uses to encode
+/// queries, cannot produce repeated keys.
+pub(crate) fn with_repeated_query<T: ToString>(path: &str, key: &str, values: &[T]) -> String {
+    if values.is_empty() {
+        return path.to_string();
+    }
+    let query = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(values.iter().map(|value| (key, value.to_string())))
+        .finish();
+    format!("{}?{}", path, query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::with_repeated_query;
+    use crate::{Asset, CurrencyPair};
+
+    #[test]
+    fn repeats_query_key_for_every_value() {
+        assert_eq!(
+            with_repeated_query("/api/1/balance", "assets", &[Asset::XBT, Asset::NGN]),
+            "/api/1/balance?assets=XBT&assets=NGN"
+        );
+        assert_eq!(
+            with_repeated_query::<Asset>("/api/1/balance", "assets", &[]),
+            "/api/1/balance"
+        );
+        assert_eq!(
+            with_repeated_query(
+                "/api/exchange/1/markets",
+                "pair",
+                &[CurrencyPair::XBTNGN, CurrencyPair::ETHNGN]
+            ),
+            "/api/exchange/1/markets?pair=XBTNGN&pair=ETHNGN"
+        );
+    }
+}
@@ -14,6 +14,14 @@
 //! luno-rs = "0.1"
 //! ```
 //!
+//! Prices, volumes and amounts are strings by default. Enable the `decimal` feature to
+//! get them as exact `rust_decimal::Decimal` values instead:
+//!
+//! ```toml
+//! [dependencies]
+//! luno-rs = { version = "0.1", features = ["decimal"] }
+//! ```
+//!
 //! ### Example usage
 //!
 //! __Using LunoClient__
@@ -65,9 +73,25 @@ mod domain;
 mod error;
 mod http;
 mod middleware;
+mod paginate;
+mod streaming;
+mod watch;
+mod withdrawals;
 
 pub use client::{LunoClient, LunoClientBuilder};
 pub use domain::{
-    AccountBalance, CurrencyPair, Order, OrderBook, OrderBookEntry, OrderType, Ticker, Trade,
+    Account, AccountBalance, Amount, Asset, BalanceSet, BalanceTotal, Beneficiary, Candle,
+    CandleDuration, CreateWithdrawalRequest, CreateWithdrawalRequestBuilder, CurrencyPair,
+    FeeEstimate, FeeEstimator, FeeInfo, FundingAddress, FundsMove, Liquidity, ListOrdersQuery,
+    ListUserTradesQuery, MarketsInfo, MoveFundsRequest, MoveFundsRequestBuilder, MoveReference,
+    MoveStatus, Order, OrderBook, OrderBookEntry, OrderDetails, OrderKind, OrderReference,
+    OrderState, OrderStatus, OrderType, PostMarketOrderRequest, PostMarketOrderRequestBuilder,
+    PostOrderRequest, PostOrderRequestBuilder, SendFee, SendRequest, SendRequestBuilder,
+    StopDirection, Ticker, TimeInForce, Trade, TradingStatus, Transaction, TransactionKind,
+    Transfer, UserTrade, Withdrawal, WithdrawalStatus,
 };
 pub use error::{Error, LunoError};
+pub use streaming::{
+    BalanceUpdate, MarketEvent, MarketStream, OrderFillUpdate, OrderStatusUpdate, StreamOrder,
+    StreamTrade, UserEvent, UserStream,
+};
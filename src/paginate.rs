@@ -0,0 +1,432 @@
+use crate::{
+    domain::{
+        Candle, CurrencyPair, GetCandlesQuery, GetCandlesResponse, ListOrdersQuery,
+        ListOrdersResponse, ListTradesResponse, ListTransactionsQuery, ListTransactionsResponse,
+        ListUserTradesQuery, ListUserTradesResponse, ListWithdrawalsQuery, ListWithdrawalsResponse,
+        Order, Trade, Transaction, UserTrade, Withdrawal,
+    },
+    error::Error,
+    http::Http,
+};
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream, StreamExt};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+struct OrderPages {
+    http: Arc<Http>,
+    query: ListOrdersQuery,
+    boundary: HashSet<String>,
+    done: bool,
+}
+
+/// Walk `/api/1/listorders` backwards in time by moving `created_before` to the oldest
+/// order of every page.
+///
+/// Orders created in the same millisecond as the oldest order of a page are requested
+/// again with the next page, so they are tracked and skipped instead of being lost.
+pub fn orders(http: Arc<Http>, query: ListOrdersQuery) -> impl Stream<Item = Result<Order, Error>> {
+    let pages = OrderPages {
+        http,
+        query,
+        boundary: HashSet::new(),
+        done: false,
+    };
+    stream::unfold(pages, |mut pages| async move {
+        if pages.done {
+            return None;
+        }
+        let response: ListOrdersResponse = match pages
+            .http
+            .process_query_request("/api/1/listorders", &pages.query)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                pages.done = true;
+                return Some((vec![Err(e)], pages));
+            }
+        };
+
+        let page = response.orders.unwrap_or_default();
+        let page_len = page.len();
+        let oldest = page
+            .iter()
+            .map(|order| order.creation_timestamp.timestamp_millis())
+            .min()?;
+        let orders: Vec<Order> = page
+            .into_iter()
+            .filter(|order| !pages.boundary.contains(&order.order_id))
+            .collect();
+        if orders.is_empty() {
+            return None;
+        }
+
+        if pages.query.created_before_millis() != Some(oldest + 1) {
+            pages.boundary.clear();
+        }
+        pages.boundary.extend(
+            orders
+                .iter()
+                .filter(|order| order.creation_timestamp.timestamp_millis() == oldest)
+                .map(|order| order.order_id.clone()),
+        );
+        pages.query.set_created_before_millis(oldest + 1);
+        if let Some(limit) = pages.query.limit() {
+            pages.done = page_len < limit as usize;
+        }
+        Some((orders.into_iter().map(Ok).collect(), pages))
+    })
+    .flat_map(stream::iter)
+}
+
+struct UserTradePages {
+    http: Arc<Http>,
+    query: ListUserTradesQuery,
+    done: bool,
+}
+
+/// Move the cursor of `query` past the page of trades with the given sequence numbers.
+/// Returns `None` if the page was empty. `before_seq` is exclusive but `after_seq` is
+/// inclusive, so ascending pages continue from the sequence after the newest trade.
+fn advance_user_trades(
+    query: &mut ListUserTradesQuery,
+    sequences: impl Iterator<Item = i64>,
+) -> Option<()> {
+    if query.is_sort_desc() {
+        query.set_before_seq(sequences.min()?);
+    } else {
+        query.set_after_seq(sequences.max()? + 1);
+    }
+    Some(())
+}
+
+/// Walk `/api/1/listtrades` by moving `after_seq` (or `before_seq` when sorting newest
+/// first) past the last trade of every page.
+pub fn user_trades(
+    http: Arc<Http>,
+    query: ListUserTradesQuery,
+) -> impl Stream<Item = Result<UserTrade, Error>> {
+    let pages = UserTradePages {
+        http,
+        query,
+        done: false,
+    };
+    stream::unfold(pages, |mut pages| async move {
+        if pages.done {
+            return None;
+        }
+        let response: ListUserTradesResponse = match pages
+            .http
+            .process_query_request("/api/1/listtrades", &pages.query)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                pages.done = true;
+                return Some((vec![Err(e)], pages));
+            }
+        };
+
+        let trades = response.trades.unwrap_or_default();
+        advance_user_trades(&mut pages.query, trades.iter().map(|trade| trade.sequence))?;
+        if let Some(limit) = pages.query.limit() {
+            pages.done = trades.len() < limit as usize;
+        }
+        Some((trades.into_iter().map(Ok).collect(), pages))
+    })
+    .flat_map(stream::iter)
+}
+
+const CANDLES_PER_PAGE: i64 = 1000;
+
+struct CandlePages {
+    http: Arc<Http>,
+    query: GetCandlesQuery,
+    until: i64,
+    done: bool,
+}
+
+/// Walk `/api/exchange/1/candles` forward in time by moving `since` one duration past the
+/// last candle of every page, until `until` is reached.
+pub fn candles(
+    http: Arc<Http>,
+    query: GetCandlesQuery,
+    until: DateTime<Utc>,
+) -> impl Stream<Item = Result<Candle, Error>> {
+    let pages = CandlePages {
+        http,
+        query,
+        until: until.timestamp_millis(),
+        done: false,
+    };
+    stream::unfold(pages, |mut pages| async move {
+        if pages.done || pages.query.since_millis() >= pages.until {
+            return None;
+        }
+        let response: GetCandlesResponse = match pages
+            .http
+            .process_query_request("/api/exchange/1/candles", &pages.query)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                pages.done = true;
+                return Some((vec![Err(e)], pages));
+            }
+        };
+
+        // A page without candles still covers a full page worth of durations, so skip over it
+        // rather than ending the stream early.
+        let duration = pages.query.duration_millis();
+        let next_since = match response
+            .candles
+            .iter()
+            .map(|candle| candle.timestamp.timestamp_millis())
+            .max()
+        {
+            Some(last) => last + duration,
+            None => pages.query.since_millis() + CANDLES_PER_PAGE * duration,
+        };
+        pages.query.set_since_millis(next_since);
+        let until = pages.until;
+        let candles = response
+            .candles
+            .into_iter()
+            .filter(|candle| candle.timestamp.timestamp_millis() < until)
+            .map(Ok)
+            .collect();
+        Some((candles, pages))
+    })
+    .flat_map(stream::iter)
+}
+
+const TRANSACTIONS_PER_PAGE: i64 = 1000;
+
+struct TransactionPages {
+    http: Arc<Http>,
+    path: String,
+    min_row: i64,
+    done: bool,
+}
+
+/// Walk `/api/1/accounts/{id}/transactions` from the first row in windows of 1000 rows.
+pub fn transactions(
+    http: Arc<Http>,
+    account_id: String,
+) -> impl Stream<Item = Result<Transaction, Error>> {
+    let pages = TransactionPages {
+        http,
+        path: format!("/api/1/accounts/{}/transactions", account_id),
+        min_row: 1,
+        done: false,
+    };
+    stream::unfold(pages, |mut pages| async move {
+        if pages.done {
+            return None;
+        }
+        let query =
+            ListTransactionsQuery::new(pages.min_row, pages.min_row + TRANSACTIONS_PER_PAGE);
+        let response: ListTransactionsResponse =
+            match pages.http.process_query_request(&pages.path, &query).await {
+                Ok(response) => response,
+                Err(e) => {
+                    pages.done = true;
+                    return Some((vec![Err(e)], pages));
+                }
+            };
+
+        let mut transactions = response.transactions.unwrap_or_default();
+        if transactions.is_empty() {
+            return None;
+        }
+        transactions.sort_by_key(|transaction| transaction.row_index);
+        pages.done = (transactions.len() as i64) < TRANSACTIONS_PER_PAGE;
+        pages.min_row = query.min_row() + TRANSACTIONS_PER_PAGE;
+        Some((transactions.into_iter().map(Ok).collect(), pages))
+    })
+    .flat_map(stream::iter)
+}
+
+struct WithdrawalPages {
+    http: Arc<Http>,
+    query: ListWithdrawalsQuery,
+    done: bool,
+}
+
+/// Walk `/api/1/withdrawals` backwards by moving `before_id` to the lowest withdrawal id of
+/// every page.
+pub fn withdrawals(
+    http: Arc<Http>,
+    query: ListWithdrawalsQuery,
+) -> impl Stream<Item = Result<Withdrawal, Error>> {
+    let pages = WithdrawalPages {
+        http,
+        query,
+        done: false,
+    };
+    stream::unfold(pages, |mut pages| async move {
+        if pages.done {
+            return None;
+        }
+        let response: ListWithdrawalsResponse = match pages
+            .http
+            .process_query_request("/api/1/withdrawals", &pages.query)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                pages.done = true;
+                return Some((vec![Err(e)], pages));
+            }
+        };
+
+        let withdrawals = response.withdrawals.unwrap_or_default();
+        let lowest = withdrawals
+            .iter()
+            .filter_map(|withdrawal| withdrawal.id.parse::<i64>().ok())
+            .min()?;
+        pages.query.set_before_id(lowest);
+        if let Some(limit) = pages.query.limit() {
+            pages.done = withdrawals.len() < limit as usize;
+        }
+        Some((withdrawals.into_iter().map(Ok).collect(), pages))
+    })
+    .flat_map(stream::iter)
+}
+
+struct TradePages {
+    http: Arc<Http>,
+    currency_pair: CurrencyPair,
+    since: i64,
+    until: i64,
+    last_sequence: Option<i64>,
+    done: bool,
+}
+
+/// Walk `/api/1/trades` forward in time from `since` up to `until` by moving `since` to the
+/// timestamp of the newest trade of every page.
+///
+/// The newest timestamp is requested again so that trades in the same millisecond are not
+/// lost; trades already seen are skipped by sequence number. A jump in sequence numbers is
+/// reported as `Error::SequenceGap` before the trades that follow it.
+pub fn trades(
+    http: Arc<Http>,
+    currency_pair: CurrencyPair,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> impl Stream<Item = Result<Trade, Error>> {
+    let pages = TradePages {
+        http,
+        currency_pair,
+        since: since.timestamp_millis(),
+        until: until.timestamp_millis(),
+        last_sequence: None,
+        done: false,
+    };
+    stream::unfold(pages, |mut pages| async move {
+        if pages.done || pages.since >= pages.until {
+            return None;
+        }
+        let path = format!(
+            "/api/1/trades?pair={}&since={}",
+            pages.currency_pair, pages.since
+        );
+        let response: ListTradesResponse = match pages.http.process_request(path).await {
+            Ok(response) => response,
+            Err(e) => {
+                pages.done = true;
+                return Some((vec![Err(e)], pages));
+            }
+        };
+
+        let trades = new_trades(response.trades, pages.last_sequence, pages.until);
+        let newest = trades.last()?;
+        pages.since = newest.timestamp.timestamp_millis();
+        let items = with_sequence_gaps(trades, pages.last_sequence);
+        pages.last_sequence = items.iter().rev().find_map(|item| match item {
+            Ok(trade) => Some(trade.sequence),
+            Err(_) => None,
+        });
+        Some((items, pages))
+    })
+    .flat_map(stream::iter)
+}
+
+/// Trades of a page that were not seen before and are older than `until`, oldest first.
+fn new_trades(mut trades: Vec<Trade>, last_sequence: Option<i64>, until: i64) -> Vec<Trade> {
+    trades.sort_by_key(|trade| trade.sequence);
+    trades
+        .into_iter()
+        .filter(|trade| last_sequence.is_none_or(|last| trade.sequence > last))
+        .filter(|trade| trade.timestamp.timestamp_millis() < until)
+        .collect()
+}
+
+/// Insert an `Error::SequenceGap` before every trade that does not directly follow the
+/// previous one.
+fn with_sequence_gaps(trades: Vec<Trade>, last_sequence: Option<i64>) -> Vec<Result<Trade, Error>> {
+    let mut items = Vec::with_capacity(trades.len());
+    let mut previous = last_sequence;
+    for trade in trades {
+        if let Some(previous) = previous {
+            if trade.sequence > previous + 1 {
+                items.push(Err(Error::SequenceGap(previous, trade.sequence)));
+            }
+        }
+        previous = Some(trade.sequence);
+        items.push(Ok(trade));
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{advance_user_trades, new_trades, with_sequence_gaps};
+    use crate::domain::{CurrencyPair, ListUserTradesQuery, Trade};
+    use crate::error::Error;
+
+    fn trade(sequence: i64, timestamp: i64) -> Trade {
+        let data = format!(
+            r#"{{"price": "100", "sequence": {}, "timestamp": {}, "is_buy": true, "volume": "1"}}"#,
+            sequence, timestamp
+        );
+        serde_json::from_str(&data).unwrap()
+    }
+
+    #[test]
+    fn advances_user_trades_past_the_page() {
+        let mut query = ListUserTradesQuery::new(CurrencyPair::XBTNGN);
+        advance_user_trades(&mut query, vec![5, 7, 6].into_iter()).unwrap();
+        assert_eq!(serde_json::to_value(&query).unwrap()["after_seq"], 8);
+
+        let mut query = ListUserTradesQuery::new(CurrencyPair::XBTNGN).with_sort_desc();
+        advance_user_trades(&mut query, vec![7, 6, 5].into_iter()).unwrap();
+        assert_eq!(serde_json::to_value(&query).unwrap()["before_seq"], 5);
+
+        assert!(advance_user_trades(&mut query, std::iter::empty()).is_none());
+    }
+
+    #[test]
+    fn skips_seen_trades_and_trades_after_until() {
+        let page = vec![
+            trade(12, 1200),
+            trade(10, 1000),
+            trade(11, 1000),
+            trade(13, 2000),
+        ];
+        let sequences: Vec<i64> = new_trades(page, Some(10), 2000)
+            .iter()
+            .map(|trade| trade.sequence)
+            .collect();
+        assert_eq!(sequences, vec![11, 12]);
+    }
+
+    #[test]
+    fn reports_gap_in_sequence_numbers() {
+        let items = with_sequence_gaps(vec![trade(11, 1000), trade(14, 1100)], Some(10));
+        assert!(matches!(items[0], Ok(ref trade) if trade.sequence == 11));
+        assert!(matches!(items[1], Err(Error::SequenceGap(11, 14))));
+        assert!(matches!(items[2], Ok(ref trade) if trade.sequence == 14));
+    }
+}
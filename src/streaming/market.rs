@@ -0,0 +1,413 @@
+use super::{connect, deserialize_sequence, next_text, supervise, with_connect_timeout, Socket};
+use crate::credential::Credential;
+use crate::domain::amount_to_f64;
+#[cfg(not(feature = "decimal"))]
+use crate::domain::{scaled_amount, subtract_amount};
+use crate::{Amount, Error, OrderBook, OrderBookEntry, OrderType, TradingStatus};
+use async_std::task;
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use futures_channel::mpsc::{self, UnboundedReceiver};
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+
+/// An order resting in the order book of a market stream.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StreamOrder {
+    pub order_id: String,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub price: Amount,
+    pub volume: Amount,
+}
+
+/// A trade reported by a market stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamTrade {
+    pub sequence: i64,
+    pub timestamp: DateTime<Utc>,
+    pub maker_order_id: String,
+    pub taker_order_id: String,
+    /// Side of the taker order
+    pub order_type: OrderType,
+    pub base: Amount,
+    pub counter: Amount,
+}
+
+/// Change applied to the order book of a market stream.
+#[derive(Debug, Clone)]
+pub enum MarketEvent {
+    Trade(StreamTrade),
+    OrderCreated(StreamOrder),
+    /// Id of the order that was removed from the book
+    OrderDeleted(String),
+    StatusChanged(TradingStatus),
+    /// The order book was replaced by a fresh snapshot after a reconnect. Holds its sequence number
+    Resynced(i64),
+}
+
+#[derive(Deserialize)]
+struct SnapshotOrder {
+    id: String,
+    price: Amount,
+    volume: Amount,
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    #[serde(deserialize_with = "deserialize_sequence")]
+    sequence: i64,
+    asks: Vec<SnapshotOrder>,
+    bids: Vec<SnapshotOrder>,
+    status: TradingStatus,
+    #[serde(with = "ts_milliseconds")]
+    timestamp: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct TradeUpdate {
+    base: Amount,
+    counter: Amount,
+    maker_order_id: String,
+    taker_order_id: String,
+}
+
+#[derive(Deserialize)]
+struct DeleteUpdate {
+    order_id: String,
+}
+
+#[derive(Deserialize)]
+struct StatusUpdate {
+    status: TradingStatus,
+}
+
+#[derive(Deserialize)]
+struct Update {
+    #[serde(deserialize_with = "deserialize_sequence")]
+    sequence: i64,
+    trade_updates: Option<Vec<TradeUpdate>>,
+    create_update: Option<StreamOrder>,
+    delete_update: Option<DeleteUpdate>,
+    status_update: Option<StatusUpdate>,
+    #[serde(with = "ts_milliseconds")]
+    timestamp: DateTime<Utc>,
+}
+
+struct BookState {
+    /// False from the moment the connection breaks off until a fresh snapshot is applied
+    synced: bool,
+    sequence: i64,
+    status: TradingStatus,
+    timestamp: DateTime<Utc>,
+    /// Resting orders by order id
+    asks: HashMap<String, OrderBookEntry>,
+    bids: HashMap<String, OrderBookEntry>,
+}
+
+impl BookState {
+    fn from_snapshot(snapshot: Snapshot) -> Self {
+        let side = |orders: Vec<SnapshotOrder>| {
+            orders
+                .into_iter()
+                .map(|order| {
+                    let entry = OrderBookEntry {
+                        price: order.price,
+                        volume: order.volume,
+                    };
+                    (order.id, entry)
+                })
+                .collect()
+        };
+        BookState {
+            synced: true,
+            sequence: snapshot.sequence,
+            status: snapshot.status,
+            timestamp: snapshot.timestamp,
+            asks: side(snapshot.asks),
+            bids: side(snapshot.bids),
+        }
+    }
+
+    fn apply(&mut self, update: Update) -> Result<Vec<MarketEvent>, Error> {
+        if update.sequence <= self.sequence {
+            return Ok(vec![]);
+        }
+        if update.sequence != self.sequence + 1 {
+            return Err(Error::SequenceGap(self.sequence, update.sequence));
+        }
+        self.sequence = update.sequence;
+        self.timestamp = update.timestamp;
+
+        let mut events = vec![];
+        for trade in update.trade_updates.unwrap_or_default() {
+            let (orders, order_type) = if self.asks.contains_key(&trade.maker_order_id) {
+                (&mut self.asks, OrderType::BID)
+            } else if self.bids.contains_key(&trade.maker_order_id) {
+                (&mut self.bids, OrderType::ASK)
+            } else {
+                return Err(Error::UnknownOrder(trade.maker_order_id));
+            };
+            let maker = orders.get_mut(&trade.maker_order_id).unwrap();
+            match remaining_volume(&maker.volume, &trade.base)? {
+                Some(remaining) => maker.volume = remaining,
+                None => {
+                    orders.remove(&trade.maker_order_id);
+                }
+            }
+            events.push(MarketEvent::Trade(StreamTrade {
+                sequence: update.sequence,
+                timestamp: update.timestamp,
+                maker_order_id: trade.maker_order_id,
+                taker_order_id: trade.taker_order_id,
+                order_type,
+                base: trade.base,
+                counter: trade.counter,
+            }));
+        }
+        if let Some(order) = update.create_update {
+            let orders = match order.order_type {
+                OrderType::ASK => &mut self.asks,
+                OrderType::BID => &mut self.bids,
+            };
+            events.push(MarketEvent::OrderCreated(order.clone()));
+            let entry = OrderBookEntry {
+                price: order.price,
+                volume: order.volume,
+            };
+            orders.insert(order.order_id, entry);
+        }
+        if let Some(delete) = update.delete_update {
+            self.asks.remove(&delete.order_id);
+            self.bids.remove(&delete.order_id);
+            events.push(MarketEvent::OrderDeleted(delete.order_id));
+        }
+        if let Some(status) = update.status_update {
+            self.status = status.status;
+            events.push(MarketEvent::StatusChanged(status.status));
+        }
+        Ok(events)
+    }
+
+    fn order_book(&self) -> OrderBook {
+        OrderBook {
+            asks: entries(&self.asks, false),
+            bids: entries(&self.bids, true),
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+fn entries(orders: &HashMap<String, OrderBookEntry>, descending: bool) -> Vec<OrderBookEntry> {
+    let price = |entry: &OrderBookEntry| amount_to_f64(&entry.price).unwrap_or_default();
+    let mut orders: Vec<(&String, &OrderBookEntry)> = orders.iter().collect();
+    orders.sort_by(|(a_id, a), (b_id, b)| {
+        let ordering = price(a).partial_cmp(&price(b)).unwrap_or(Ordering::Equal);
+        let ordering = if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+        ordering.then_with(|| a_id.cmp(b_id))
+    });
+    orders.into_iter().map(|(_, entry)| entry.clone()).collect()
+}
+
+/// Volume left on an order after `filled` of it traded, or `None` if nothing is left.
+#[cfg(not(feature = "decimal"))]
+fn remaining_volume(volume: &Amount, filled: &Amount) -> Result<Option<Amount>, Error> {
+    let remaining = subtract_amount(volume, filled)?;
+    Ok(Some(remaining)
+        .filter(|remaining| matches!(scaled_amount(remaining), Ok((digits, _)) if digits > 0)))
+}
+
+/// Volume left on an order after `filled` of it traded, or `None` if nothing is left.
+#[cfg(feature = "decimal")]
+fn remaining_volume(volume: &Amount, filled: &Amount) -> Result<Option<Amount>, Error> {
+    let remaining = *volume - *filled;
+    Ok(Some(remaining).filter(|remaining| remaining.is_sign_positive() && !remaining.is_zero()))
+}
+
+/// Connect to the market stream and read the order book snapshot it starts with.
+async fn open(url: &str, credential: &Credential) -> Result<(Socket, Snapshot), Error> {
+    let mut socket = connect(url, credential).await?;
+    let snapshot = next_text(&mut socket).await.unwrap_or_else(|| {
+        Err(Error::WebSocketError(
+            "Connection closed before the order book snapshot".to_string(),
+        ))
+    })?;
+    let snapshot: Snapshot = serde_json::from_str(&snapshot)?;
+    Ok((socket, snapshot))
+}
+
+/// Live view of a market, kept up to date from Luno's streaming API.
+///
+/// Polling the stream yields every change applied to the order book. When the
+/// connection drops or an update does not follow on from the last one, the stream
+/// yields the error and reconnects. Until it has replaced the order book with a fresh
+/// snapshot and yielded `MarketEvent::Resynced`, `is_synced` returns false and the
+/// order book is stale. The stream only ends once it is dropped.
+pub struct MarketStream {
+    state: Arc<RwLock<BookState>>,
+    events: UnboundedReceiver<Result<MarketEvent, Error>>,
+}
+
+impl MarketStream {
+    pub(crate) async fn connect(url: String, credential: Credential) -> Result<Self, Error> {
+        let (socket, snapshot) = with_connect_timeout(open(&url, &credential)).await?;
+        let state = Arc::new(RwLock::new(BookState::from_snapshot(snapshot)));
+        let (sender, events) = mpsc::unbounded();
+        let book = state.clone();
+        let stale = state.clone();
+        let resync = state.clone();
+        task::spawn(supervise(
+            socket,
+            move |message| {
+                let update: Update = serde_json::from_str(message)?;
+                book.write().unwrap().apply(update)
+            },
+            move || stale.write().unwrap().synced = false,
+            move || {
+                let (url, credential, resync) = (url.clone(), credential.clone(), resync.clone());
+                async move {
+                    let (socket, snapshot) = open(&url, &credential).await?;
+                    let mut book = resync.write().unwrap();
+                    if snapshot.sequence < book.sequence {
+                        return Err(Error::SequenceGap(book.sequence, snapshot.sequence));
+                    }
+                    *book = BookState::from_snapshot(snapshot);
+                    Ok((socket, MarketEvent::Resynced(book.sequence)))
+                }
+            },
+            sender,
+        ));
+        Ok(MarketStream { state, events })
+    }
+
+    /// Current order book, with asks in ascending and bids in descending price order.
+    /// The book is stale while `is_synced` returns false.
+    pub fn order_book(&self) -> OrderBook {
+        self.state.read().unwrap().order_book()
+    }
+
+    /// Whether the order book is being kept up to date. False while the stream reconnects.
+    pub fn is_synced(&self) -> bool {
+        self.state.read().unwrap().synced
+    }
+
+    /// Sequence number of the last update applied to the order book.
+    pub fn sequence(&self) -> i64 {
+        self.state.read().unwrap().sequence
+    }
+
+    /// Trading status of the market.
+    pub fn status(&self) -> TradingStatus {
+        self.state.read().unwrap().status
+    }
+}
+
+impl Stream for MarketStream {
+    type Item = Result<MarketEvent, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.events).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BookState;
+    use crate::streaming::serve;
+    use crate::{CurrencyPair, Error, LunoClientBuilder, MarketEvent, OrderType, TradingStatus};
+    use futures_util::StreamExt;
+
+    const SNAPSHOT: &str = r#"{"sequence":"10","asks":[{"id":"A1","price":"101.00","volume":"0.93"},{"id":"A2","price":"100.00","volume":"1.00"}],"bids":[{"id":"B1","price":"99.00","volume":"2.00"}],"status":"ACTIVE","timestamp":1528884331021}"#;
+
+    #[async_std::test]
+    async fn applies_stream_updates_to_order_book() {
+        let url = serve(vec![vec![
+            SNAPSHOT,
+            r#""#,
+            r#"{"sequence":"11","trade_updates":[{"base":"0.40","counter":"40.00","maker_order_id":"A2","taker_order_id":"B9"}],"create_update":{"order_id":"B2","type":"BID","price":"99.50","volume":"1.00"},"delete_update":null,"status_update":null,"timestamp":1528884331022}"#,
+            r#"{"sequence":"12","trade_updates":null,"create_update":null,"delete_update":{"order_id":"A1"},"status_update":null,"timestamp":1528884331023}"#,
+        ]])
+        .await;
+        let client = LunoClientBuilder::new("key".into(), "secret".into())
+            .with_stream_url(url)
+            .build();
+        let mut stream = client.market_stream(CurrencyPair::XBTZAR).await.unwrap();
+
+        match stream.next().await {
+            Some(Ok(MarketEvent::Trade(trade))) => {
+                assert_eq!(trade.maker_order_id, "A2");
+                assert_eq!(trade.order_type, OrderType::BID);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(MarketEvent::OrderCreated(_)))
+        ));
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(MarketEvent::OrderDeleted(id))) if id == "A1"
+        ));
+
+        assert_eq!(stream.sequence(), 12);
+        let book = stream.order_book();
+        assert_eq!(book.asks.len(), 1);
+        assert_eq!(book.asks[0].volume.to_string(), "0.60");
+        let bids: Vec<String> = book.bids.iter().map(|b| b.price.to_string()).collect();
+        assert_eq!(bids, vec!["99.50", "99.00"]);
+    }
+
+    #[test]
+    fn trade_against_unknown_order_is_rejected() {
+        let mut book = BookState::from_snapshot(serde_json::from_str(SNAPSHOT).unwrap());
+        let update = serde_json::from_str(r#"{"sequence":"11","trade_updates":[{"base":"0.10","counter":"10.00","maker_order_id":"X9","taker_order_id":"B9"}],"create_update":null,"delete_update":null,"status_update":null,"timestamp":1528884331022}"#).unwrap();
+        assert!(matches!(book.apply(update), Err(Error::UnknownOrder(id)) if id == "X9"));
+    }
+
+    #[async_std::test]
+    async fn resyncs_after_a_sequence_gap() {
+        let url = serve(vec![
+            vec![
+                SNAPSHOT,
+                r#"{"sequence":"12","trade_updates":null,"create_update":null,"delete_update":{"order_id":"B1"},"status_update":null,"timestamp":1528884331024}"#,
+            ],
+            vec![r#"{"sequence":"5","asks":[],"bids":[],"status":"ACTIVE","timestamp":1528884331000}"#],
+            vec![r#"{"sequence":"20","asks":[{"id":"A3","price":"102.00","volume":"1.00"}],"bids":[],"status":"POST_ONLY","timestamp":1528884331030}"#],
+        ])
+        .await;
+        let client = LunoClientBuilder::new("key".into(), "secret".into())
+            .with_stream_url(url)
+            .build();
+        let mut stream = client.market_stream(CurrencyPair::XBTZAR).await.unwrap();
+
+        // The gap at 12 and the stale snapshot at 5 are both yielded and followed by a reconnect
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::SequenceGap(10, 12)))
+        ));
+        assert!(!stream.is_synced());
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::SequenceGap(10, 5)))
+        ));
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(MarketEvent::Resynced(20)))
+        ));
+        assert!(stream.is_synced());
+        assert_eq!(stream.sequence(), 20);
+        assert_eq!(stream.status(), TradingStatus::PostOnly);
+        let book = stream.order_book();
+        assert_eq!(book.asks[0].price.to_string(), "102.00");
+        assert!(book.bids.is_empty());
+    }
+}
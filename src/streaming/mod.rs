@@ -0,0 +1,285 @@
+mod market;
+mod user;
+pub use market::{MarketEvent, MarketStream, StreamOrder, StreamTrade};
+pub use user::{BalanceUpdate, OrderFillUpdate, OrderStatusUpdate, UserEvent, UserStream};
+
+use crate::credential::Credential;
+use crate::Error;
+use async_std::future::timeout;
+use async_std::task;
+use async_tungstenite::async_std::{connect_async, ConnectStream};
+use async_tungstenite::tungstenite::Message;
+use async_tungstenite::WebSocketStream;
+use futures_channel::mpsc::UnboundedSender;
+use futures_util::{SinkExt, StreamExt};
+use serde::{de, Serialize};
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+const STREAM_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+/// Limit on opening a connection, including the snapshot the market stream starts with.
+const STREAM_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+const RECONNECT_MIN_DELAY: Duration = Duration::from_millis(500);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+pub(crate) type Socket = WebSocketStream<ConnectStream>;
+
+#[derive(Serialize)]
+struct AuthMessage<'a> {
+    api_key_id: &'a str,
+    api_key_secret: &'a str,
+}
+
+/// Open a WebSocket connection and authenticate it with the API key.
+pub(crate) async fn connect(url: &str, credential: &Credential) -> Result<Socket, Error> {
+    let (mut socket, _) = connect_async(url).await?;
+    let auth = AuthMessage {
+        api_key_id: &credential.key_id,
+        api_key_secret: &credential.key_secret,
+    };
+    socket
+        .send(Message::Text(serde_json::to_string(&auth)?))
+        .await?;
+    Ok(socket)
+}
+
+/// Fail with a timeout error if opening a connection takes too long, so that a server
+/// which accepts the socket but never answers does not stall the stream.
+pub(crate) async fn with_connect_timeout<T, Fut>(connecting: Fut) -> Result<T, Error>
+where
+    Fut: Future<Output = Result<T, Error>>,
+{
+    timeout(STREAM_CONNECT_TIMEOUT, connecting).await?
+}
+
+/// Read the next JSON message from the socket, skipping keep-alives.
+/// Returns `None` once the server closes the connection.
+pub(crate) async fn next_text(socket: &mut Socket) -> Option<Result<String, Error>> {
+    while let Some(message) = socket.next().await {
+        match message {
+            Ok(Message::Text(text)) => {
+                let text = text.trim();
+                if text.is_empty() || text == "\"\"" {
+                    continue;
+                }
+                return Some(Ok(text.to_string()));
+            }
+            Ok(Message::Close(_)) => return None,
+            Ok(_) => continue,
+            Err(err) => return Some(Err(err.into())),
+        }
+    }
+    None
+}
+
+/// Turn every message read from the socket into events, setting `received` once a
+/// message arrives. Returns `Ok` once the receiving end is dropped and the reason the
+/// connection broke off otherwise.
+async fn forward<E, F>(
+    socket: &mut Socket,
+    handle: &mut F,
+    events: &UnboundedSender<Result<E, Error>>,
+    received: &mut bool,
+) -> Result<(), Error>
+where
+    F: FnMut(&str) -> Result<Vec<E>, Error>,
+{
+    loop {
+        let message = timeout(STREAM_IDLE_TIMEOUT, next_text(socket))
+            .await?
+            .unwrap_or_else(|| Err(Error::WebSocketError("Connection closed".to_string())))?;
+        *received = true;
+        for event in handle(&message)? {
+            if events.unbounded_send(Ok(event)).is_err() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Delay before the next reconnect attempt: exponential in the number of failed
+/// attempts, capped, with jitter so that many clients do not reconnect in lockstep.
+pub(crate) fn backoff(failures: u32) -> Duration {
+    let delay = RECONNECT_MIN_DELAY
+        .saturating_mul(2u32.saturating_pow(failures.saturating_sub(1)))
+        .min(RECONNECT_MAX_DELAY);
+    delay.mul_f64(0.5 + fastrand::f64() / 2.0)
+}
+
+/// Forward events from the socket until the receiving end is dropped. Whenever the
+/// connection breaks off or a message cannot be applied, call `disconnected`, send the
+/// error and reconnect with backoff, sending the error of every failed attempt too.
+/// Once the stream is back in sync the event returned by `reconnect` is sent.
+///
+/// The backoff only resets once a connection has delivered a message, so a server that
+/// accepts connections and closes them straight away is not reconnected to in a tight loop.
+pub(crate) async fn supervise<E, F, D, C, Fut>(
+    mut socket: Socket,
+    mut handle: F,
+    mut disconnected: D,
+    mut reconnect: C,
+    events: UnboundedSender<Result<E, Error>>,
+) where
+    F: FnMut(&str) -> Result<Vec<E>, Error>,
+    D: FnMut(),
+    C: FnMut() -> Fut,
+    Fut: Future<Output = Result<(Socket, E), Error>>,
+{
+    let mut failures = 0;
+    loop {
+        let mut received = false;
+        match forward(&mut socket, &mut handle, &events, &mut received).await {
+            Ok(()) => return,
+            Err(err) => {
+                warn!("Stream interrupted, reconnecting: {}", err);
+                disconnected();
+                if events.unbounded_send(Err(err)).is_err() {
+                    return;
+                }
+            }
+        }
+        if received {
+            failures = 0;
+        }
+        let resynced = loop {
+            failures += 1;
+            task::sleep(backoff(failures)).await;
+            if events.is_closed() {
+                return;
+            }
+            match with_connect_timeout(reconnect()).await {
+                Ok(resynced) => break resynced,
+                Err(err) => {
+                    warn!("Reconnect attempt {} failed: {}", failures, err);
+                    if events.unbounded_send(Err(err)).is_err() {
+                        return;
+                    }
+                }
+            }
+        };
+        socket = resynced.0;
+        if events.unbounded_send(Ok(resynced.1)).is_err() {
+            return;
+        }
+    }
+}
+
+struct SequenceVisitor;
+/// Luno sends stream sequence numbers as strings.
+pub fn deserialize_sequence<'de, D>(d: D) -> Result<i64, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    d.deserialize_any(SequenceVisitor)
+}
+
+impl<'de> de::Visitor<'de> for SequenceVisitor {
+    type Value = i64;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "sequence should be an integer or a string")
+    }
+    fn visit_str<E>(self, sequence: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        sequence.parse().map_err(E::custom)
+    }
+    fn visit_i64<E>(self, sequence: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(sequence)
+    }
+    fn visit_u64<E>(self, sequence: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(sequence as i64)
+    }
+}
+
+/// Serve each session of messages over a local WebSocket to the next client that
+/// connects. All but the last session close the connection once sent.
+#[cfg(test)]
+pub(crate) async fn serve(sessions: Vec<Vec<&'static str>>) -> String {
+    use async_std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    task::spawn(async move {
+        let last = sessions.len() - 1;
+        for (session, messages) in sessions.into_iter().enumerate() {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = async_tungstenite::accept_async(stream).await.unwrap();
+            let auth = socket.next().await.unwrap().unwrap();
+            assert!(auth.to_text().unwrap().contains("\"api_key_id\":\"key\""));
+            for message in messages {
+                socket.send(Message::Text(message.into())).await.unwrap();
+            }
+            if session == last {
+                while socket.next().await.is_some() {}
+            } else {
+                let _ = socket.close(None).await;
+            }
+        }
+    });
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{backoff, RECONNECT_MAX_DELAY, RECONNECT_MIN_DELAY};
+    use crate::LunoClientBuilder;
+    use async_std::future::timeout;
+    use async_std::net::TcpListener;
+    use async_std::task;
+    use futures_util::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[async_std::test]
+    async fn backs_off_when_every_connection_is_closed_at_once() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+        let accepted = connections.clone();
+        task::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                accepted.fetch_add(1, Ordering::SeqCst);
+                let mut socket = async_tungstenite::accept_async(stream).await.unwrap();
+                let _ = socket.next().await;
+                let _ = socket.close(None).await;
+            }
+        });
+        let client = LunoClientBuilder::new("key".into(), "secret".into())
+            .with_stream_url(url)
+            .build();
+        let mut stream = client.user_stream().await.unwrap();
+
+        let mut errors = 0;
+        let _ = timeout(Duration::from_secs(2), async {
+            while let Some(event) = stream.next().await {
+                if event.is_err() {
+                    errors += 1;
+                }
+            }
+        })
+        .await;
+        // Reconnects are at least 250ms, 500ms and 1s apart, so 2 seconds allow no more than 4
+        assert!(connections.load(Ordering::SeqCst) <= 4);
+        assert!(errors <= 4);
+    }
+
+    #[test]
+    fn backs_off_exponentially_with_jitter() {
+        for _ in 0..100 {
+            let first = backoff(1);
+            assert!(first >= RECONNECT_MIN_DELAY / 2 && first <= RECONNECT_MIN_DELAY);
+            let third = backoff(3);
+            assert!(third >= Duration::from_secs(1) && third <= Duration::from_secs(2));
+            assert!(backoff(40) <= RECONNECT_MAX_DELAY);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use super::{supervise, with_connect_timeout};
+use crate::credential::Credential;
+use crate::{Amount, Error, OrderStatus};
+use async_std::task;
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use futures_channel::mpsc::{self, UnboundedReceiver};
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Change in the status of one of the user's orders.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderStatusUpdate {
+    /// Matches `Order.order_id`
+    pub order_id: String,
+    pub market_id: String,
+    pub status: OrderStatus,
+    #[serde(skip_deserializing, with = "ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Fill of one of the user's orders. The `*_fill` and `*_fee` amounts are totals
+/// for the order so far and the `*_delta` amounts are for this fill only.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrderFillUpdate {
+    /// Matches `Order.order_id`
+    pub order_id: String,
+    pub market_id: String,
+    pub base_fill: Amount,
+    pub counter_fill: Amount,
+    pub base_delta: Amount,
+    pub counter_delta: Amount,
+    pub base_fee: Amount,
+    pub counter_fee: Amount,
+    pub base_fee_delta: Amount,
+    pub counter_fee_delta: Amount,
+    #[serde(skip_deserializing, with = "ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Change in the balance of one of the user's accounts.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BalanceUpdate {
+    /// Matches `AccountBalance.account_id`
+    pub account_id: String,
+    pub row_index: i64,
+    pub balance: Amount,
+    pub balance_delta: Amount,
+    pub available: Amount,
+    pub available_delta: Amount,
+    #[serde(skip_deserializing, with = "ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Event received on the user stream.
+#[derive(Debug, Clone)]
+pub enum UserEvent {
+    OrderStatus(OrderStatusUpdate),
+    OrderFill(OrderFillUpdate),
+    Balance(BalanceUpdate),
+    /// The stream reconnected and events may have been missed while it was down.
+    /// Re-fetch orders and balances to catch up
+    Resynced,
+}
+
+#[derive(Deserialize)]
+struct UserMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(with = "ts_milliseconds")]
+    timestamp: DateTime<Utc>,
+    order_status_update: Option<OrderStatusUpdate>,
+    order_fill_update: Option<OrderFillUpdate>,
+    balance_update: Option<BalanceUpdate>,
+}
+
+impl UserMessage {
+    /// Messages of a type this client does not know about are skipped.
+    fn into_event(self) -> Option<UserEvent> {
+        let timestamp = self.timestamp;
+        match self.kind.as_str() {
+            "order_status" => self.order_status_update.map(|mut update| {
+                update.timestamp = timestamp;
+                UserEvent::OrderStatus(update)
+            }),
+            "order_fill" => self.order_fill_update.map(|mut update| {
+                update.timestamp = timestamp;
+                UserEvent::OrderFill(update)
+            }),
+            "balance_update" => self.balance_update.map(|mut update| {
+                update.timestamp = timestamp;
+                UserEvent::Balance(update)
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Order and balance events of the authenticated user, received from Luno's streaming API.
+///
+/// When the connection drops the stream yields the error, reconnects and then yields
+/// `UserEvent::Resynced`. The stream only ends once it is dropped.
+pub struct UserStream {
+    events: UnboundedReceiver<Result<UserEvent, Error>>,
+}
+
+impl UserStream {
+    pub(crate) async fn connect(url: String, credential: Credential) -> Result<Self, Error> {
+        let socket = with_connect_timeout(super::connect(&url, &credential)).await?;
+        let (sender, events) = mpsc::unbounded();
+        task::spawn(supervise(
+            socket,
+            |message| {
+                let message: UserMessage = serde_json::from_str(message)?;
+                Ok(message.into_event().into_iter().collect())
+            },
+            || {},
+            move || {
+                let (url, credential) = (url.clone(), credential.clone());
+                async move {
+                    let socket = super::connect(&url, &credential).await?;
+                    Ok((socket, UserEvent::Resynced))
+                }
+            },
+            sender,
+        ));
+        Ok(UserStream { events })
+    }
+}
+
+impl Stream for UserStream {
+    type Item = Result<UserEvent, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.events).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::streaming::serve;
+    use crate::{LunoClientBuilder, OrderStatus, UserEvent};
+    use futures_util::StreamExt;
+
+    #[async_std::test]
+    async fn yields_typed_user_events_across_reconnects() {
+        let url = serve(vec![vec![
+            r#"{"type":"order_status","timestamp":1600000000000,"order_status_update":{"order_id":"BXMC2CJ7HNB88U4","market_id":"XBTZAR","status":"COMPLETE"}}"#,
+            r#"{"type":"order_fill","timestamp":1600000000001,"order_fill_update":{"order_id":"BXMC2CJ7HNB88U4","market_id":"XBTZAR","base_fill":"0.10","counter_fill":"5000.00","base_delta":"0.04","counter_delta":"2000.00","base_fee":"0.0001","counter_fee":"0.00","base_fee_delta":"0.00004","counter_fee_delta":"0.00"}}"#,
+            r#"{"type":"future_update","timestamp":1600000000002}"#,
+            r#"{"type":"balance_update","timestamp":1600000000003,"balance_update":{"account_id":"319232323","row_index":42,"balance":"1.10","balance_delta":"0.04","available":"1.00","available_delta":"0.04"}}"#,
+        ], vec![
+            r#"{"type":"order_status","timestamp":1600000000004,"order_status_update":{"order_id":"BXMC2CJ7HNB88U5","market_id":"XBTZAR","status":"PENDING"}}"#,
+        ]])
+        .await;
+        let client = LunoClientBuilder::new("key".into(), "secret".into())
+            .with_stream_url(url)
+            .build();
+        let mut stream = client.user_stream().await.unwrap();
+
+        match stream.next().await {
+            Some(Ok(UserEvent::OrderStatus(update))) => {
+                assert_eq!(update.order_id, "BXMC2CJ7HNB88U4");
+                assert_eq!(update.status, OrderStatus::Complete);
+                assert_eq!(update.timestamp.timestamp_millis(), 1600000000000);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        match stream.next().await {
+            Some(Ok(UserEvent::OrderFill(update))) => {
+                assert_eq!(update.base_delta.to_string(), "0.04")
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        match stream.next().await {
+            Some(Ok(UserEvent::Balance(update))) => {
+                assert_eq!(update.account_id, "319232323");
+                assert_eq!(update.row_index, 42);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert!(matches!(stream.next().await, Some(Err(_))));
+        assert!(matches!(stream.next().await, Some(Ok(UserEvent::Resynced))));
+        match stream.next().await {
+            Some(Ok(UserEvent::OrderStatus(update))) => {
+                assert_eq!(update.status, OrderStatus::Pending)
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+}
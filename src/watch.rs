@@ -0,0 +1,167 @@
+use crate::{
+    client::LunoClient,
+    domain::{CurrencyPair, OrderBook, Ticker},
+    error::Error,
+    streaming::backoff,
+};
+use async_std::task;
+use futures_util::{stream, Stream};
+use std::future::Future;
+use std::time::Duration;
+
+struct Watch<T, F> {
+    fetch: F,
+    interval: Duration,
+    last: Option<T>,
+    failures: u32,
+    polled: bool,
+}
+
+/// Poll `fetch` every `interval` and yield values that differ from the last one
+/// yielded. Errors are yielded too, after which polling continues with backoff.
+fn watch<T, F, Fut>(
+    interval: Duration,
+    fetch: F,
+    unchanged: fn(&T, &T) -> bool,
+) -> impl Stream<Item = Result<T, Error>>
+where
+    T: Clone,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let state = Watch {
+        fetch,
+        interval,
+        last: None,
+        failures: 0,
+        polled: false,
+    };
+    stream::unfold(state, move |mut state| async move {
+        loop {
+            if state.polled {
+                let delay = match state.failures {
+                    0 => state.interval,
+                    failures => backoff(failures).max(state.interval),
+                };
+                task::sleep(delay).await;
+            }
+            state.polled = true;
+            match (state.fetch)().await {
+                Ok(value) => {
+                    state.failures = 0;
+                    if matches!(&state.last, Some(last) if unchanged(last, &value)) {
+                        continue;
+                    }
+                    state.last = Some(value.clone());
+                    return Some((Ok(value), state));
+                }
+                Err(err) => {
+                    state.failures += 1;
+                    return Some((Err(err), state));
+                }
+            }
+        }
+    })
+}
+
+impl LunoClient {
+    /// Poll the ticker of a currency pair every `interval` and yield it whenever it changes.
+    /// Useful where the streaming API cannot be reached.
+    ///
+    /// Errors are yielded without ending the stream, and polling backs off while they persist.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use luno_rs::{CurrencyPair, LunoClient};
+    /// use std::env;
+    /// use std::time::Duration;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let mut tickers = Box::pin(client.watch_ticker(CurrencyPair::XBTNGN, Duration::from_secs(1)));
+    /// while let Some(ticker) = tickers.next().await {
+    ///     let ticker = ticker.unwrap();
+    ///     println!("bid: {}, ask: {}", ticker.bid, ticker.ask);
+    /// }
+    /// # }
+    /// ```
+    pub fn watch_ticker(
+        &self,
+        currency_pair: CurrencyPair,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Ticker, Error>> {
+        let client = self.clone();
+        watch(
+            interval,
+            move || {
+                let client = client.clone();
+                async move { client.get_ticker(currency_pair).await }
+            },
+            // The timestamp moves on with every poll, so it is left out of the comparison
+            |last, ticker| {
+                last.bid == ticker.bid
+                    && last.ask == ticker.ask
+                    && last.last_trade == ticker.last_trade
+                    && last.rolling_24_hour_volume == ticker.rolling_24_hour_volume
+                    && last.status == ticker.status
+            },
+        )
+    }
+
+    /// Poll the top 100 bids and asks of a currency pair every `interval` and yield them
+    /// whenever they change. Useful where the streaming API cannot be reached.
+    ///
+    /// Errors are yielded without ending the stream, and polling backs off while they persist.
+    pub fn watch_order_book(
+        &self,
+        currency_pair: CurrencyPair,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<OrderBook, Error>> {
+        let client = self.clone();
+        watch(
+            interval,
+            move || {
+                let client = client.clone();
+                async move { client.get_order_book_top_100(currency_pair).await }
+            },
+            |last, order_book| last.asks == order_book.asks && last.bids == order_book.bids,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::watch;
+    use crate::Error;
+    use futures_util::StreamExt;
+    use std::time::Duration;
+
+    #[async_std::test]
+    async fn yields_only_changes_and_errors() {
+        let mut responses = vec![
+            Ok(1),
+            Ok(1),
+            Ok(2),
+            Err(Error::TimeoutError("timed out".to_string())),
+            Ok(2),
+            Ok(3),
+        ]
+        .into_iter();
+        let values: Vec<_> = watch(
+            Duration::from_millis(1),
+            move || std::future::ready(responses.next().unwrap()),
+            |last: &i32, value| last == value,
+        )
+        .take(4)
+        .collect()
+        .await;
+        assert!(matches!(
+            values.as_slice(),
+            [Ok(1), Ok(2), Err(Error::TimeoutError(_)), Ok(3)]
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    client::LunoClient,
+    domain::{CreateWithdrawalRequest, ListWithdrawalsQuery, ListWithdrawalsResponse, Withdrawal},
+    error::Error,
+    paginate,
+};
+use futures_util::Stream;
+use surf::http::Method;
+
+impl LunoClient {
+    /// List withdrawals on Luno profile, newest first, optionally only those with an id
+    /// less than `before_id`
+    pub async fn list_withdrawals(
+        &self,
+        before_id: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Withdrawal>, Error> {
+        let query = ListWithdrawalsQuery::new(before_id, limit);
+        let response: ListWithdrawalsResponse = self
+            .http
+            .process_query_request("/api/1/withdrawals", &query)
+            .await?;
+        Ok(response.withdrawals.unwrap_or_default())
+    }
+
+    /// Stream all withdrawals on Luno profile, newest first, fetching `page_size`
+    /// withdrawals per request
+    pub fn list_withdrawals_stream(
+        &self,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<Withdrawal, Error>> {
+        let query = ListWithdrawalsQuery::new(None, Some(page_size));
+        paginate::withdrawals(self.http.clone(), query)
+    }
+
+    /// Request a withdrawal
+    ///
+    /// # Example
+    /// ```no_run
+    /// use luno_rs::{CreateWithdrawalRequestBuilder, LunoClient};
+    /// use std::env;
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() {
+    /// #    let key_id = env::var("LUNO_KEY_ID").unwrap();
+    /// #    let key_secret = env::var("LUNO_KEY_SECRET").unwrap();
+    /// let client = LunoClient::new(key_id, key_secret);
+    /// let request = CreateWithdrawalRequestBuilder::new("NGN_DEFAULT", "500000.00")
+    ///     .with_beneficiary_id("1234")
+    ///     .with_reference("Treasury sweep")
+    ///     .build();
+    /// let withdrawal = client.create_withdrawal(request).await.unwrap();
+    /// println!("{} -> {:?}", withdrawal.id, withdrawal.status);
+    /// # }
+    /// ```
+    pub async fn create_withdrawal(
+        &self,
+        request: CreateWithdrawalRequest,
+    ) -> Result<Withdrawal, Error> {
+        let response: Withdrawal = self
+            .http
+            .process_form_request(Method::Post, "/api/1/withdrawals", &request)
+            .await?;
+        Ok(response)
+    }
+
+    /// Get a withdrawal by its id
+    pub async fn get_withdrawal(&self, withdrawal_id: &str) -> Result<Withdrawal, Error> {
+        let path = format!("/api/1/withdrawals/{}", withdrawal_id);
+        let response: Withdrawal = self.http.process_request(path).await?;
+        Ok(response)
+    }
+
+    /// Cancel a withdrawal that is still pending
+    pub async fn cancel_withdrawal(&self, withdrawal_id: &str) -> Result<Withdrawal, Error> {
+        let path = format!("/api/1/withdrawals/{}", withdrawal_id);
+        let response: Withdrawal = self.http.process_delete_request(path).await?;
+        Ok(response)
+    }
+}